@@ -3,7 +3,7 @@
 
 #![allow(unused)]
 
-use crate::{FromAccountView, WritableAllowed};
+use crate::{AccountFlags, FromAccountView, WritableAllowed};
 use core::ops::{Deref, DerefMut};
 use hayabusa_common::{address_eq, AccountView, Address, Ref, RefMut};
 use hayabusa_errors::{ErrorCode, ProgramError, Result};
@@ -39,6 +39,8 @@ impl<'ix, T> CheckedAddress<'ix, T>
 where
     T: RawZcDeserializeUnchecked,
 {
+    /// # Safety
+    /// See [`RawZcDeserializeUnchecked::try_deserialize_raw_unchecked`].
     #[inline(always)]
     pub unsafe fn try_deserialize_unchecked(&self) -> Result<&'ix T> {
         T::try_deserialize_raw_unchecked(self.account_view)
@@ -69,6 +71,8 @@ impl<'ix, T> CheckedAddress<'ix, T>
 where 
     T: RawZcDeserializeUncheckedMut,
 {
+    /// # Safety
+    /// See [`RawZcDeserializeUncheckedMut::try_deserialize_raw_unchecked_mut`].
     #[inline(always)]
     pub unsafe fn try_deserialize_raw_unchecked_mut(&self) -> Result<&'ix mut T> {
         T::try_deserialize_raw_unchecked_mut(self.account_view)
@@ -110,6 +114,8 @@ impl<'ix, T> Deref for CheckedAddress<'ix, T> {
 
 impl<T> WritableAllowed for CheckedAddress<'_, T> {}
 
+impl<T> AccountFlags for CheckedAddress<'_, T> {}
+
 pub struct CheckedAddressMeta<'a> {
     pub addr: &'a Address,
 }
@@ -1,7 +1,7 @@
 // Copyright (c) 2026, Arcane Labs <dev@arcane.fi>
 // SPDX-License-Identifier: Apache-2.0
 
-use crate::{FromAccountView, NoMeta, ProgramIds, ToAccountView};
+use crate::{AccountFlags, FromAccountView, NoMeta, ProgramIds, ToAccountView};
 use core::ops::Deref;
 use hayabusa_common::AccountView;
 use hayabusa_errors::{ErrorCode, ProgramError, Result};
@@ -60,6 +60,8 @@ where
     }
 }
 
+impl<T> AccountFlags for Interface<'_, T> where T: ProgramIds {}
+
 impl<T> Deref for Interface<'_, T>
 where
     T: ProgramIds,
@@ -68,6 +70,6 @@ where
 
     #[inline(always)]
     fn deref(&self) -> &Self::Target {
-        &self.account_view
+        self.account_view
     }
 }
@@ -1,7 +1,7 @@
 // Copyright (c) 2026, Arcane Labs <dev@arcane.fi>
 // SPDX-License-Identifier: Apache-2.0
 
-use crate::{FromAccountView, WritableAllowed};
+use crate::{AccountFlags, FromAccountView, WritableAllowed};
 use core::ops::{Deref, DerefMut};
 use hayabusa_common::AccountView;
 use hayabusa_errors::{ErrorCode, ProgramError, Result};
@@ -37,6 +37,14 @@ where
     }
 }
 
+impl<T> AccountFlags for Mut<T>
+where
+    T: AccountFlags,
+{
+    const IS_SIGNER: bool = T::IS_SIGNER;
+    const IS_WRITABLE: bool = true;
+}
+
 impl<'ix, T> Deref for Mut<T>
 where
     T: FromAccountView<'ix> + WritableAllowed,
@@ -3,7 +3,7 @@
 
 #![allow(unused)]
 
-use crate::{FromAccountView, WritableAllowed};
+use crate::{AccountFlags, FromAccountView, WritableAllowed};
 use core::ops::Deref;
 use hayabusa_common::{AccountView, Ref, RefMut};
 use hayabusa_errors::Result;
@@ -103,11 +103,13 @@ where
 
 impl<T: CheckSeeds> WritableAllowed for Pda<'_, T> {}
 
+impl<T: CheckSeeds> AccountFlags for Pda<'_, T> {}
+
 impl<T: CheckSeeds> Deref for Pda<'_, T> {
     type Target = AccountView;
 
     #[inline(always)]
     fn deref(&self) -> &Self::Target {
-        &self.view
+        self.view
     }
 }
\ No newline at end of file
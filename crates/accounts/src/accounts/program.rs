@@ -1,7 +1,7 @@
 // Copyright (c) 2026, Arcane Labs <dev@arcane.fi>
 // SPDX-License-Identifier: Apache-2.0
 
-use crate::{FromAccountView, NoMeta, ProgramId, ToAccountView};
+use crate::{AccountFlags, FromAccountView, NoMeta, ProgramId, ToAccountView};
 use core::ops::Deref;
 use hayabusa_common::{address_eq, AccountView, Address};
 use hayabusa_errors::{ErrorCode, ProgramError, Result};
@@ -60,12 +60,14 @@ where
     }
 }
 
+impl<T: ProgramId> AccountFlags for Program<'_, T> {}
+
 impl<T: ProgramId> Deref for Program<'_, T> {
     type Target = AccountView;
 
     #[inline(always)]
     fn deref(&self) -> &Self::Target {
-        &self.account_view
+        self.account_view
     }
 }
 
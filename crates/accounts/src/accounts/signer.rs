@@ -1,7 +1,7 @@
 // Copyright (c) 2026, Arcane Labs <dev@arcane.fi>
 // SPDX-License-Identifier: Apache-2.0
 
-use crate::{FromAccountView, NoMeta, ToAccountView, WritableAllowed};
+use crate::{AccountFlags, FromAccountView, NoMeta, ToAccountView, WritableAllowed};
 use core::ops::Deref;
 use hayabusa_common::{AccountView, Address};
 use hayabusa_errors::{ErrorCode, ProgramError, Result};
@@ -49,11 +49,15 @@ impl ToAccountView for Signer<'_> {
 
 impl WritableAllowed for Signer<'_> {}
 
+impl AccountFlags for Signer<'_> {
+    const IS_SIGNER: bool = true;
+}
+
 impl Deref for Signer<'_> {
     type Target = AccountView;
 
     #[inline(always)]
     fn deref(&self) -> &Self::Target {
-        &self.account_view
+        self.account_view
     }
 }
@@ -1,7 +1,7 @@
 // Copyright (c) 2026, Arcane Labs <dev@arcane.fi>
 // SPDX-License-Identifier: Apache-2.0
 
-use crate::{FromAccountView, NoMeta, ToAccountView, WritableAllowed};
+use crate::{AccountFlags, FromAccountView, NoMeta, ToAccountView, WritableAllowed};
 use core::ops::Deref;
 use hayabusa_common::AccountView;
 use hayabusa_errors::{ErrorCode, ProgramError, Result};
@@ -42,11 +42,13 @@ impl ToAccountView for SystemAccount<'_> {
 
 impl WritableAllowed for SystemAccount<'_> {}
 
+impl AccountFlags for SystemAccount<'_> {}
+
 impl Deref for SystemAccount<'_> {
     type Target = AccountView;
 
     #[inline(always)]
     fn deref(&self) -> &Self::Target {
-        &self.account_view
+        self.account_view
     }
 }
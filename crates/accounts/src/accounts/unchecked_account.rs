@@ -1,7 +1,7 @@
 // Copyright (c) 2026, Arcane Labs <dev@arcane.fi>
 // SPDX-License-Identifier: Apache-2.0
 
-use crate::{FromAccountView, NoMeta, ToAccountView, WritableAllowed};
+use crate::{AccountFlags, FromAccountView, NoMeta, ToAccountView, WritableAllowed};
 use hayabusa_common::AccountView;
 use hayabusa_errors::Result;
 
@@ -40,3 +40,5 @@ impl core::ops::Deref for UncheckedAccount<'_> {
 }
 
 impl WritableAllowed for UncheckedAccount<'_> {}
+
+impl AccountFlags for UncheckedAccount<'_> {}
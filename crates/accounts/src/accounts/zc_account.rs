@@ -1,7 +1,7 @@
 // Copyright (c) 2026, Arcane Labs <dev@arcane.fi>
 // SPDX-License-Identifier: Apache-2.0
 
-use crate::{FromAccountView, NoMeta, ToAccountView, WritableAllowed};
+use crate::{AccountFlags, FromAccountView, NoMeta, ToAccountView, WritableAllowed};
 use core::ops::Deref;
 use hayabusa_common::{AccountView, Ref, RefMut};
 use hayabusa_errors::Result;
@@ -26,7 +26,7 @@ where
     T: ZcDeserialize,
 {
     #[inline(always)]
-    pub fn try_deserialize(&self) -> Result<Ref<T>> {
+    pub fn try_deserialize(&self) -> Result<Ref<'_, T>> {
         T::try_deserialize(self.account_view)
     }
 }
@@ -37,7 +37,7 @@ where
     T: ZcDeserialize + ZcDeserializeMut,
 {
     #[inline(always)]
-    pub fn try_deserialize_mut(&self) -> Result<RefMut<T>> {
+    pub fn try_deserialize_mut(&self) -> Result<RefMut<'_, T>> {
         T::try_deserialize_mut(self.account_view)
     }
 }
@@ -61,7 +61,7 @@ where
     T: RawZcDeserialize,
 {
     #[inline(always)]
-    pub fn try_deserialize_raw(&self) -> Result<Ref<T>> {
+    pub fn try_deserialize_raw(&self) -> Result<Ref<'_, T>> {
         T::try_deserialize_raw(self.account_view)
     }
 }
@@ -71,7 +71,7 @@ where
     T: RawZcDeserialize + RawZcDeserializeMut,
 {
     #[inline(always)]
-    pub fn try_deserialize_raw_mut(&self) -> Result<RefMut<T>> {
+    pub fn try_deserialize_raw_mut(&self) -> Result<RefMut<'_, T>> {
         T::try_deserialize_raw_mut(self.account_view)
     }
 }
@@ -80,6 +80,8 @@ impl<T> ZcAccount<'_, T>
 where
     T: RawZcDeserializeUnchecked,
 {
+    /// # Safety
+    /// See [`RawZcDeserializeUnchecked::try_deserialize_raw_unchecked`].
     #[inline(always)]
     pub unsafe fn try_deserialize_raw_unchecked(&self) -> Result<&T> {
         T::try_deserialize_raw_unchecked(self.account_view)
@@ -90,6 +92,9 @@ impl<T> ZcAccount<'_, T>
 where
     T: RawZcDeserializeUnchecked + RawZcDeserializeUncheckedMut,
 {
+    /// # Safety
+    /// See [`RawZcDeserializeUncheckedMut::try_deserialize_raw_unchecked_mut`].
+    #[allow(clippy::mut_from_ref)]
     #[inline(always)]
     pub unsafe fn try_deserialize_raw_unchecked_mut(&self) -> Result<&mut T> {
         T::try_deserialize_raw_unchecked_mut(self.account_view)
@@ -129,6 +134,8 @@ where
 
 impl<T> WritableAllowed for ZcAccount<'_, T> where T: Zc + Deserialize {}
 
+impl<T> AccountFlags for ZcAccount<'_, T> where T: Zc + Deserialize {}
+
 impl<T> Deref for ZcAccount<'_, T>
 where
     T: Zc + Deserialize,
@@ -137,6 +144,6 @@ where
 
     #[inline(always)]
     fn deref(&self) -> &Self::Target {
-        &self.account_view
+        self.account_view
     }
 }
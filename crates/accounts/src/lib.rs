@@ -11,9 +11,11 @@ pub use accounts::{
 
 use hayabusa_common::{AccountView, Address};
 use hayabusa_errors::Result;
+use solana_instruction_view::InstructionAccount;
 
 /// Trait for types that can be constructed from a single account view with optional metadata.
-/// 
+///
+/// # Safety
 /// This trait is unsafe because implementors can create UB in their implementations. Soundness must be 
 /// ensured by the implementor.
 ///
@@ -200,6 +202,32 @@ where
 /// ```
 pub trait WritableAllowed {}
 
+/// Signer and writable flags an account type requires in the instruction's account list.
+///
+/// Used by host-side clients and the generated CPI helpers to build account lists in the same
+/// order and with the same flags that `FromAccountViews` will validate on-chain. Both flags default to `false`;
+/// `Signer` sets `IS_SIGNER` and `Mut<T>` sets `IS_WRITABLE` on top of `T`'s flags.
+///
+/// # Example
+/// ```ignore
+/// // Mut<Signer<'ix>> -> writable signer
+/// assert!(<Mut<Signer<'_>> as AccountFlags>::IS_SIGNER);
+/// assert!(<Mut<Signer<'_>> as AccountFlags>::IS_WRITABLE);
+/// ```
+pub trait AccountFlags {
+    /// Whether the account must sign the transaction.
+    const IS_SIGNER: bool = false;
+
+    /// Whether the account must be passed as writable.
+    const IS_WRITABLE: bool = false;
+
+    /// Builds the CPI `InstructionAccount` for `address` using this type's flags.
+    #[inline(always)]
+    fn instruction_account(address: &Address) -> InstructionAccount<'_> {
+        InstructionAccount::new(address, Self::IS_WRITABLE, Self::IS_SIGNER)
+    }
+}
+
 /// Trait for types representing a single program.
 ///
 /// Provides compile-time access to the program's address for validation and CPI.
@@ -0,0 +1,49 @@
+// Copyright (c) 2026, Arcane Labs <dev@arcane.fi>
+// SPDX-License-Identifier: Apache-2.0
+
+use hayabusa_common::Address;
+use hayabusa_errors::Result;
+use hayabusa_pda::find_program_address;
+
+/// Derives the associated token account of `wallet` for `mint`, returning its address and bump.
+///
+/// `token_program` is the program owning `mint`, either Token or Token-2022; each yields a
+/// different address for the same wallet and mint.
+#[inline]
+pub fn find_associated_token_address(
+    wallet: &Address,
+    mint: &Address,
+    token_program: &Address,
+) -> Result<(Address, u8)> {
+    find_program_address(
+        &[wallet.as_ref(), token_program.as_ref(), mint.as_ref()],
+        &crate::ID,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hayabusa_accounts::ProgramIds;
+    use hayabusa_token_interface::TokenInterface;
+
+    #[test]
+    fn derives_per_token_program() {
+        let wallet = Address::new_from_array([1; 32]);
+        let mint = Address::new_from_array([2; 32]);
+        let [token, token2022] = TokenInterface::IDS else {
+            unreachable!()
+        };
+
+        let (address, bump) = find_associated_token_address(&wallet, &mint, token).unwrap();
+        let expected = Address::create_program_address(
+            &[wallet.as_ref(), token.as_ref(), mint.as_ref(), &[bump]],
+            &crate::ID,
+        )
+        .unwrap();
+        assert_eq!(address, expected);
+
+        let (address_2022, _) = find_associated_token_address(&wallet, &mint, token2022).unwrap();
+        assert_ne!(address, address_2022);
+    }
+}
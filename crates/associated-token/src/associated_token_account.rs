@@ -0,0 +1,93 @@
+// Copyright (c) 2026, Arcane Labs <dev@arcane.fi>
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::find_associated_token_address;
+use core::ops::Deref;
+use hayabusa_accounts::{AccountFlags, FromAccountView, ProgramIds, ToAccountView, WritableAllowed};
+use hayabusa_common::{address_eq, AccountView, Address};
+use hayabusa_errors::{ErrorCode, ProgramError, Result};
+use hayabusa_token_interface::TokenInterface;
+use hayabusa_utility::{error_msg, hint::unlikely};
+
+/// Canonical associated token account of a (wallet, mint, token program) triple.
+///
+/// The account may not exist yet, so it can be passed to `create_idempotent` in the same
+/// instruction; once it holds data it must be owned by the token program.
+pub struct AssociatedTokenAccount<'ix> {
+    pub account_view: &'ix AccountView,
+}
+
+unsafe impl<'ix> FromAccountView<'ix> for AssociatedTokenAccount<'ix> {
+    type Meta<'a>
+        = AssociatedTokenAccountMeta<'a>
+    where
+        'ix: 'a;
+
+    #[inline(always)]
+    fn try_from_account_view<'a>(account_view: &'ix AccountView, meta: Self::Meta<'a>) -> Result<Self>
+    where
+        'ix: 'a,
+    {
+        if unlikely(!TokenInterface::IDS.contains(meta.token_program)) {
+            error_msg!(
+                "AssociatedTokenAccount::try_from_account_view: invalid token program",
+                ErrorCode::InvalidProgram,
+            );
+        }
+
+        let (address, _) = find_associated_token_address(meta.wallet, meta.mint, meta.token_program)?;
+
+        if unlikely(!address_eq(account_view.address(), &address)) {
+            error_msg!(
+                "AssociatedTokenAccount::try_from_account_view: not the associated token account",
+                ErrorCode::InvalidAccount,
+            );
+        }
+
+        if unlikely(!account_view.is_data_empty() && !account_view.owned_by(meta.token_program)) {
+            error_msg!(
+                "AssociatedTokenAccount::try_from_account_view: invalid account owner, must be token program",
+                ErrorCode::InvalidAccount,
+            );
+        }
+
+        Ok(AssociatedTokenAccount { account_view })
+    }
+}
+
+impl ToAccountView for AssociatedTokenAccount<'_> {
+    #[inline(always)]
+    fn to_account_view(&self) -> &AccountView {
+        self.account_view
+    }
+}
+
+impl WritableAllowed for AssociatedTokenAccount<'_> {}
+
+impl AccountFlags for AssociatedTokenAccount<'_> {}
+
+impl Deref for AssociatedTokenAccount<'_> {
+    type Target = AccountView;
+
+    #[inline(always)]
+    fn deref(&self) -> &Self::Target {
+        self.account_view
+    }
+}
+
+pub struct AssociatedTokenAccountMeta<'a> {
+    pub wallet: &'a Address,
+    pub mint: &'a Address,
+    pub token_program: &'a Address,
+}
+
+impl<'a> AssociatedTokenAccountMeta<'a> {
+    #[inline(always)]
+    pub fn new(wallet: &'a Address, mint: &'a Address, token_program: &'a Address) -> Self {
+        Self {
+            wallet,
+            mint,
+            token_program,
+        }
+    }
+}
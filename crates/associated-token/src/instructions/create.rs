@@ -0,0 +1,28 @@
+// Copyright (c) 2026, Arcane Labs <dev@arcane.fi>
+// SPDX-License-Identifier: Apache-2.0
+
+use hayabusa_common::{AccountView, Address};
+use hayabusa_cpi::{CheckProgramId, CpiCtx, CpiData};
+use hayabusa_cpi_accounts_derive::CpiAccounts;
+use hayabusa_errors::Result;
+use solana_instruction_view::{InstructionAccount, InstructionView};
+
+#[derive(CpiAccounts)]
+#[cpi(program = crate::ID, discriminator = [0])]
+pub struct Create<'ix> {
+    /// Funding account
+    #[writable]
+    #[signer]
+    pub payer: &'ix AccountView,
+    /// Associated token account to create
+    #[writable]
+    pub associated_token_account: &'ix AccountView,
+    /// Wallet owning the new account
+    pub wallet: &'ix AccountView,
+    /// Token mint
+    pub mint: &'ix AccountView,
+    /// System program
+    pub system_program: &'ix AccountView,
+    /// Token program owning the mint
+    pub token_program: &'ix AccountView,
+}
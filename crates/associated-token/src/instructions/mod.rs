@@ -0,0 +1,10 @@
+// Copyright (c) 2026, Arcane Labs <dev@arcane.fi>
+// SPDX-License-Identifier: Apache-2.0
+
+pub mod create;
+pub mod create_idempotent;
+pub mod recover_nested;
+
+pub use create::*;
+pub use create_idempotent::*;
+pub use recover_nested::*;
@@ -0,0 +1,31 @@
+// Copyright (c) 2026, Arcane Labs <dev@arcane.fi>
+// SPDX-License-Identifier: Apache-2.0
+
+use hayabusa_common::{AccountView, Address};
+use hayabusa_cpi::{CheckProgramId, CpiCtx, CpiData};
+use hayabusa_cpi_accounts_derive::CpiAccounts;
+use hayabusa_errors::Result;
+use solana_instruction_view::{InstructionAccount, InstructionView};
+
+#[derive(CpiAccounts)]
+#[cpi(program = crate::ID, discriminator = [2])]
+pub struct RecoverNested<'ix> {
+    /// Nested associated token account, owned by `owner_associated_token_account`
+    #[writable]
+    pub nested_associated_token_account: &'ix AccountView,
+    /// Mint of the nested account
+    pub nested_mint: &'ix AccountView,
+    /// Wallet's associated token account for `nested_mint`, receiving the tokens
+    #[writable]
+    pub destination_associated_token_account: &'ix AccountView,
+    /// Wallet's associated token account for `owner_mint`
+    pub owner_associated_token_account: &'ix AccountView,
+    /// Mint of the owner account
+    pub owner_mint: &'ix AccountView,
+    /// Wallet owning both associated token accounts
+    #[writable]
+    #[signer]
+    pub wallet: &'ix AccountView,
+    /// Token program owning the mints
+    pub token_program: &'ix AccountView,
+}
@@ -0,0 +1,22 @@
+// Copyright (c) 2026, Arcane Labs <dev@arcane.fi>
+// SPDX-License-Identifier: Apache-2.0
+
+#![no_std]
+
+mod address;
+mod associated_token_account;
+pub mod instructions;
+
+pub use address::*;
+pub use associated_token_account::*;
+
+hayabusa_common::declare_id!("ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL");
+
+use hayabusa_accounts::ProgramId;
+use hayabusa_common::Address;
+
+pub struct AssociatedToken;
+
+impl ProgramId for AssociatedToken {
+    const ID: Address = ID;
+}
@@ -0,0 +1,125 @@
+// Copyright (c) 2026, Arcane Labs <dev@arcane.fi>
+// SPDX-License-Identifier: Apache-2.0
+
+//! Host-side helpers used by the `client` module generated by `#[program]`.
+//!
+//! Nothing in this crate is meant to be compiled for the SBF target. Programs expose it
+//! behind a `client` feature:
+//! ```toml
+//! [features]
+//! client = ["hayabusa/client"]
+//! ```
+
+use bytemuck::AnyBitPattern;
+use hayabusa_accounts::AccountFlags;
+use hayabusa_discriminator::Discriminator;
+use hayabusa_errors::{ProgramError, Result};
+use hayabusa_events::EVENT_AUTHORITY_SEED;
+use hayabusa_utility::Len;
+use solana_address::Address;
+
+pub use solana_instruction::{AccountMeta, Instruction};
+
+/// Trait for account address sets that can be turned into an instruction's account list.
+///
+/// Implemented by the `*Accounts` structs generated by `#[derive(FromAccountViews)]` when the
+/// `client` feature is enabled. Metas are returned in field declaration order, which is the
+/// order `FromAccountViews` consumes them on-chain.
+pub trait ToAccountMetas {
+    fn to_account_metas(&self) -> Vec<AccountMeta>;
+}
+
+/// Builds the `AccountMeta` for `pubkey` using the flags required by the account type `T`.
+#[inline(always)]
+pub fn account_meta<T: AccountFlags>(pubkey: Address) -> AccountMeta {
+    AccountMeta {
+        pubkey,
+        is_signer: T::IS_SIGNER,
+        is_writable: T::IS_WRITABLE,
+    }
+}
+
+/// Trait for decoding raw account data into an `#[account]` struct on the host.
+///
+/// Checks the data length and the 8-byte discriminator before reading the struct, the
+/// same checks `try_deserialize_zc` performs on-chain (minus the owner check, since only
+/// the bytes are available here).
+pub trait AccountDecode: Sized {
+    fn try_decode(data: &[u8]) -> Result<Self>;
+}
+
+impl<T> AccountDecode for T
+where
+    T: AnyBitPattern + Discriminator + Len,
+{
+    fn try_decode(data: &[u8]) -> Result<Self> {
+        if data.len() != T::DISCRIMINATED_LEN {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        if &data[..8] != T::DISCRIMINATOR {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        Ok(bytemuck::pod_read_unaligned(&data[8..]))
+    }
+}
+
+/// Address of the event authority PDA of a `#[program(event_cpi)]` program, for the
+/// `event_authority` account of instructions that call `emit_cpi!`.
+#[inline]
+pub fn event_authority_address(program_id: &Address) -> Address {
+    Address::find_program_address(&[EVENT_AUTHORITY_SEED], program_id).0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Clone, Copy)]
+    #[repr(C)]
+    struct Counter {
+        count: u64,
+    }
+
+    unsafe impl bytemuck::Zeroable for Counter {}
+    unsafe impl bytemuck::Pod for Counter {}
+
+    impl Discriminator for Counter {
+        const DISCRIMINATOR: &'static [u8] = &[1, 2, 3, 4, 5, 6, 7, 8];
+    }
+
+    impl Len for Counter {}
+
+    #[test]
+    fn decode_checks_discriminator_and_len() {
+        let mut data = Counter::DISCRIMINATOR.to_vec();
+        data.extend_from_slice(&42u64.to_le_bytes());
+
+        assert_eq!(Counter::try_decode(&data).unwrap().count, 42);
+
+        data[0] = 0;
+        assert_eq!(
+            Counter::try_decode(&data).err(),
+            Some(ProgramError::InvalidAccountData)
+        );
+        assert_eq!(
+            Counter::try_decode(&data[..12]).err(),
+            Some(ProgramError::InvalidAccountData)
+        );
+    }
+
+    #[test]
+    fn event_authority_matches_const_derivation() {
+        let program_id = Address::new_from_array([7; 32]);
+
+        assert_eq!(
+            hayabusa_events::find_event_authority(&program_id),
+            Address::find_program_address(&[EVENT_AUTHORITY_SEED], &program_id),
+        );
+        assert_eq!(
+            hayabusa_events::find_event_authority(&program_id).0,
+            event_authority_address(&program_id),
+        );
+    }
+}
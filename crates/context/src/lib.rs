@@ -3,7 +3,7 @@
 
 #![no_std]
 
-use hayabusa_common::AccountView;
+use hayabusa_common::{AccountView, Address};
 use hayabusa_errors::{ErrorCode, ProgramError, Result};
 use hayabusa_utility::{error_msg, hint::unlikely};
 
@@ -38,6 +38,24 @@ where
     fn try_from_account_views(account_views: &mut AccountIter<'ix>) -> Result<Self>;
 }
 
+/// Logs which account of a `FromAccountViews` struct failed, called by the derive when
+/// the `debug-accounts` feature is enabled.
+///
+/// `address` is `None` when the account was missing.
+#[cold]
+#[inline(never)]
+pub fn log_account_failure(account_struct: &str, field: &str, index: usize, address: Option<&Address>) {
+    match address {
+        Some(address) => {
+            pinocchio_log::log!("{}::{} (account {}) failed validation:", account_struct, field, index);
+            hayabusa_syscalls::log_address(address);
+        }
+        None => {
+            pinocchio_log::log!("{}::{} (account {}) is missing", account_struct, field, index);
+        }
+    }
+}
+
 /// Instruction context containing validated accounts and any remaining unparsed accounts.
 ///
 /// This is the primary way to access accounts in an instruction handler. The `Ctx` type
@@ -203,6 +221,7 @@ impl<'ix> AccountIter<'ix> {
     }
 
     /// Get the next &AccountView in the iterator
+    #[allow(clippy::should_implement_trait)]
     #[inline(always)]
     pub fn next(&mut self) -> Result<&'ix AccountView> {
         if unlikely(self.ptr == self.end) {
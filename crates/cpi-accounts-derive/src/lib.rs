@@ -0,0 +1,472 @@
+// Copyright (c) 2026, Arcane Labs <dev@arcane.fi>
+// SPDX-License-Identifier: Apache-2.0
+
+use heck::ToSnakeCase;
+use proc_macro::TokenStream;
+use proc_macro2::Span;
+use quote::{format_ident, quote};
+use syn::{
+    parenthesized,
+    parse::{Parse, ParseStream},
+    parse_macro_input, parse_quote,
+    punctuated::Punctuated,
+    spanned::Spanned,
+    visit_mut::VisitMut,
+    Data, DeriveInput, Error, Expr, Field, Fields, Ident, Lifetime, Meta, MetaNameValue,
+    Result as SynResult, Token, Type,
+};
+
+/// Usage:
+///   #[derive(CpiAccounts)]
+///   #[cpi(program = crate::ID, discriminator = [12], data(amount: u64, decimals: u8))]
+///   pub struct TransferChecked<'ix> {
+///       #[writable]
+///       pub from: &'ix AccountView,
+///       pub mint: &'ix AccountView,
+///       #[writable]
+///       pub to: &'ix AccountView,
+///       #[signer]
+///       pub authority: &'ix AccountView,
+///   }
+///
+/// Generates the `CheckProgramId` impl, `account_views()` / `instruction_accounts()` in field
+/// order, `instruction_data(amount, decimals)` (the discriminator followed by each `data`
+/// argument, `CpiField` encoding) and `pub fn transfer_checked(cpi_ctx, amount, decimals)`,
+/// which invokes the program with the context's signers.
+///
+/// `programs = expr` replaces `program` when the instruction is shared by several programs,
+/// e.g. `programs = TokenInterface::IDS`: the context accepts any of them and invokes the one
+/// it was given.
+///
+/// A `data` argument written `name: Type = expr` is computed from `cpi_ctx` instead of being
+/// a parameter, e.g. `base: &Address = cpi_ctx.base.address()`.
+///
+/// `name = ident` overrides the function name (snake case of the struct by default), and
+/// `returns = Type` makes it return the callee's `Pod` return data.
+///
+/// A last field `#[remaining(max = N)] pub signers: &'ix [&'ix AccountView]` appends a
+/// variable number of accounts (flags apply to each), e.g. multisig signers; the accounts are
+/// then assembled in the function instead of `account_views()` / `instruction_accounts()`.
+/// Several `#[remaining]` fields may close the struct, e.g. multisig signers followed by
+/// source accounts, and are appended in order. An authority marked `#[signer(multisig)]` only
+/// signs when no signers are passed in the first `#[remaining]` field marked `#[signer]`.
+#[proc_macro_derive(CpiAccounts, attributes(cpi, writable, signer, remaining))]
+pub fn derive_cpi_accounts(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    expand_cpi_accounts(&input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+/// Options of the `#[cpi(...)]` struct attribute.
+struct CpiOptions {
+    /// Slice of the accepted program IDs.
+    programs: Expr,
+    discriminator: Expr,
+    data: Vec<DataArg>,
+    name: Option<Ident>,
+    returns: Option<Type>,
+}
+
+/// `ident: Type` (or `ident: Type = expr`) entry of `data(...)`.
+struct DataArg {
+    ident: Ident,
+    ty: Type,
+    value: Option<Expr>,
+}
+
+impl Parse for DataArg {
+    fn parse(input: ParseStream) -> SynResult<Self> {
+        let ident = input.parse()?;
+        input.parse::<Token![:]>()?;
+        let ty = input.parse()?;
+        let value = if input.parse::<Option<Token![=]>>()?.is_some() {
+            Some(input.parse()?)
+        } else {
+            None
+        };
+
+        Ok(Self { ident, ty, value })
+    }
+}
+
+impl Parse for CpiOptions {
+    fn parse(input: ParseStream) -> SynResult<Self> {
+        let mut programs = None;
+        let mut discriminator = None;
+        let mut data = Vec::new();
+        let mut name = None;
+        let mut returns = None;
+
+        while !input.is_empty() {
+            let option: Ident = input.parse()?;
+
+            match option.to_string().as_str() {
+                "program" => {
+                    input.parse::<Token![=]>()?;
+                    let program: Expr = input.parse()?;
+                    programs = Some(parse_quote! { &[#program] });
+                }
+                "programs" => {
+                    input.parse::<Token![=]>()?;
+                    programs = Some(input.parse()?);
+                }
+                "discriminator" => {
+                    input.parse::<Token![=]>()?;
+                    discriminator = Some(input.parse()?);
+                }
+                "name" => {
+                    input.parse::<Token![=]>()?;
+                    name = Some(input.parse()?);
+                }
+                "returns" => {
+                    input.parse::<Token![=]>()?;
+                    returns = Some(input.parse()?);
+                }
+                "data" => {
+                    let content;
+                    parenthesized!(content in input);
+                    data = Punctuated::<DataArg, Token![,]>::parse_terminated(&content)?
+                        .into_iter()
+                        .collect();
+                }
+                _ => return Err(Error::new(option.span(), "unknown #[cpi] option")),
+            }
+
+            if !input.is_empty() {
+                input.parse::<Token![,]>()?;
+            }
+        }
+
+        Ok(Self {
+            programs: programs.ok_or_else(|| {
+                Error::new(input.span(), "#[cpi] requires `program = ...` or `programs = ...`")
+            })?,
+            discriminator: discriminator.ok_or_else(|| {
+                Error::new(input.span(), "#[cpi] requires `discriminator = [...]`")
+            })?,
+            data,
+            name,
+            returns,
+        })
+    }
+}
+
+fn expand_cpi_accounts(input: &DeriveInput) -> SynResult<proc_macro2::TokenStream> {
+    let struct_name = &input.ident;
+    let vis = &input.vis;
+
+    let info_lt = match input.generics.lifetimes().collect::<Vec<_>>().as_slice() {
+        [lt] => lt.lifetime.clone(),
+        _ => {
+            return Err(Error::new(
+                input.span(),
+                "CpiAccounts requires exactly one lifetime parameter",
+            ))
+        }
+    };
+
+    let fields = match &input.data {
+        Data::Struct(s) => match &s.fields {
+            Fields::Named(n) => &n.named,
+            _ => {
+                return Err(Error::new(
+                    s.fields.span(),
+                    "CpiAccounts supports named fields only",
+                ))
+            }
+        },
+        _ => {
+            return Err(Error::new(
+                input.span(),
+                "CpiAccounts can only be derived for structs",
+            ))
+        }
+    };
+
+    let Some(attr) = input.attrs.iter().find(|a| a.path().is_ident("cpi")) else {
+        return Err(Error::new(
+            input.span(),
+            "CpiAccounts requires a #[cpi(...)] attribute",
+        ));
+    };
+    let options: CpiOptions = attr.parse_args()?;
+
+    // the remaining accounts come last, so they are known before the account flags are built
+    let is_remaining = |f: &Field| f.attrs.iter().any(|a| a.path().is_ident("remaining"));
+    let remaining_fields = match fields.iter().position(is_remaining) {
+        Some(i) => {
+            if let Some(field) = fields.iter().skip(i).find(|f| !is_remaining(f)) {
+                return Err(Error::new(
+                    field.span(),
+                    "#[remaining] fields must be the last fields",
+                ));
+            }
+            fields.iter().skip(i).collect()
+        }
+        None => Vec::new(),
+    };
+    // the multisig signers
+    let remaining = remaining_fields
+        .iter()
+        .find(|f| f.attrs.iter().any(|a| a.path().is_ident("signer")));
+
+    let mut field_idents = Vec::new();
+    let mut instruction_accounts = Vec::new();
+    let mut ctx_instruction_accounts = Vec::new();
+    let mut remaining_accounts = Vec::new();
+    for field in fields {
+        let ident = field.ident.as_ref().unwrap();
+        let is_writable = field.attrs.iter().any(|a| a.path().is_ident("writable"));
+        let signer = field.attrs.iter().find(|a| a.path().is_ident("signer"));
+
+        // `#[signer(multisig)]`: signs unless the multisig signers are passed
+        let is_multisig = match signer {
+            Some(attr) if !matches!(attr.meta, Meta::Path(_)) => {
+                let arg: Ident = attr.parse_args()?;
+                if arg != "multisig" {
+                    return Err(Error::new(arg.span(), "expected `multisig`"));
+                }
+                true
+            }
+            _ => false,
+        };
+
+        let constructor = match (is_writable, signer.is_some()) {
+            (true, true) => quote! { writable_signer },
+            (true, false) => quote! { writable },
+            (false, true) => quote! { readonly_signer },
+            (false, false) => quote! { readonly },
+        };
+
+        if is_remaining(field) {
+            let attr = field
+                .attrs
+                .iter()
+                .find(|a| a.path().is_ident("remaining"))
+                .unwrap();
+            let max: MetaNameValue = attr.parse_args()?;
+            if !max.path.is_ident("max") {
+                return Err(Error::new(max.path.span(), "expected `max = ...`"));
+            }
+
+            remaining_accounts.push((ident, max.value, constructor));
+            continue;
+        }
+
+        if is_multisig {
+            let Some(remaining) = remaining.and_then(|r| r.ident.as_ref()) else {
+                return Err(Error::new(
+                    field.span(),
+                    "#[signer(multisig)] requires a #[remaining] #[signer] field for the signers",
+                ));
+            };
+
+            ctx_instruction_accounts.push(quote! {
+                InstructionAccount::new(
+                    cpi_ctx.accounts.#ident.address(),
+                    #is_writable,
+                    cpi_ctx.accounts.#remaining.is_empty(),
+                )
+            });
+        } else {
+            ctx_instruction_accounts.push(
+                quote! { InstructionAccount::#constructor(cpi_ctx.accounts.#ident.address()) },
+            );
+        }
+        instruction_accounts
+            .push(quote! { InstructionAccount::#constructor(self.#ident.address()) });
+        field_idents.push(ident);
+    }
+
+    let len = field_idents.len();
+    let programs = &options.programs;
+    let discriminator = &options.discriminator;
+    let fn_name = options
+        .name
+        .clone()
+        .unwrap_or_else(|| format_ident!("{}", struct_name.to_string().to_snake_case()));
+    let doc = format!("Invokes the instruction taking [`{struct_name}`] through CPI.");
+
+    let arg_idents = options
+        .data
+        .iter()
+        .map(|arg| &arg.ident)
+        .collect::<Vec<_>>();
+    let arg_tys = options.data.iter().map(|arg| &arg.ty).collect::<Vec<_>>();
+
+    let mut params = Vec::new();
+    let mut computed = Vec::new();
+    for DataArg { ident, ty, value } in &options.data {
+        match value {
+            Some(value) => computed.push(quote! { let #ident: #ty = #value; }),
+            None => params.push(quote! { #ident: #ty }),
+        }
+    }
+
+    // sizes are summed in a const context where only elided lifetimes can be named
+    let size_tys = arg_tys
+        .iter()
+        .map(|ty| {
+            let mut ty = (*ty).clone();
+            ElideLifetimes.visit_type_mut(&mut ty);
+            ty
+        })
+        .collect::<Vec<_>>();
+
+    let (fn_ret, ret_value) = match &options.returns {
+        Some(ty) => (quote! { #ty }, quote! { cpi_ctx.return_value() }),
+        None => (quote! { () }, quote! { Ok(()) }),
+    };
+
+    let (account_methods, invoke_cpi) = match remaining_accounts.as_slice() {
+        [] => (
+            quote! {
+                /// Account views in the order the instruction consumes them.
+                #[inline(always)]
+                pub fn account_views(&self) -> [&#info_lt AccountView; #len] {
+                    [#(self.#field_idents,)*]
+                }
+
+                /// Instruction accounts with the flags the program expects.
+                #[inline(always)]
+                pub fn instruction_accounts(&self) -> [InstructionAccount<#info_lt>; #len] {
+                    [#(#instruction_accounts,)*]
+                }
+            },
+            quote! {
+                let account_views = cpi_ctx.account_views();
+                let instruction_accounts = cpi_ctx.instruction_accounts();
+
+                let instruction = InstructionView {
+                    program_id: cpi_ctx.program.address(),
+                    accounts: &instruction_accounts,
+                    data: ix_data.as_slice(),
+                };
+
+                cpi_ctx.invoke(&instruction, &account_views)?;
+            },
+        ),
+        remaining_accounts => {
+            let Some(first) = field_idents.first() else {
+                return Err(Error::new(
+                    input.span(),
+                    "#[remaining] requires at least one other account",
+                ));
+            };
+            let remaining = remaining_accounts
+                .iter()
+                .map(|(ident, ..)| ident)
+                .collect::<Vec<_>>();
+            let maxes = remaining_accounts
+                .iter()
+                .map(|(_, max, _)| max)
+                .collect::<Vec<_>>();
+            let ends = (0..remaining.len())
+                .map(|i| format_ident!("end_{}", i))
+                .collect::<Vec<_>>();
+            let starts = core::iter::once(quote! { #len })
+                .chain(ends.iter().map(|end| quote! { #end }))
+                .take(ends.len())
+                .collect::<Vec<_>>();
+            let last_end = ends.last().unwrap();
+
+            // each remaining account takes the flags of the field it was passed in
+            let mut remaining_instruction_account = quote! {};
+            for (i, (_, _, constructor)) in remaining_accounts.iter().enumerate().rev() {
+                let end = &ends[i];
+                let account =
+                    quote! { InstructionAccount::#constructor(account_views[i].address()) };
+                remaining_instruction_account = if i + 1 == remaining_accounts.len() {
+                    account
+                } else {
+                    quote! { if i < #end { #account } else { #remaining_instruction_account } }
+                };
+            }
+
+            (
+                quote! {},
+                quote! {
+                    const MAX_ACCOUNTS: usize = #len #(+ #maxes)*;
+
+                    #(
+                        if cpi_ctx.accounts.#remaining.len() > #maxes {
+                            return Err(ProgramError::InvalidArgument);
+                        }
+                    )*
+                    #(let #ends = #starts + cpi_ctx.accounts.#remaining.len();)*
+                    let len = #last_end;
+
+                    // unused slots repeat the first account and are sliced off
+                    let mut account_views = [cpi_ctx.accounts.#first; MAX_ACCOUNTS];
+                    account_views[..#len].copy_from_slice(&[#(cpi_ctx.accounts.#field_idents,)*]);
+                    #(account_views[#starts..#ends].copy_from_slice(cpi_ctx.accounts.#remaining);)*
+
+                    let fixed_accounts = [#(#ctx_instruction_accounts,)*];
+                    let instruction_accounts: [InstructionAccount; MAX_ACCOUNTS] =
+                        core::array::from_fn(|i| match fixed_accounts.get(i) {
+                            Some(account) => account.clone(),
+                            None => #remaining_instruction_account,
+                        });
+
+                    let instruction = InstructionView {
+                        program_id: cpi_ctx.program.address(),
+                        accounts: &instruction_accounts[..len],
+                        data: ix_data.as_slice(),
+                    };
+
+                    cpi_ctx.invoke_with_bounds::<MAX_ACCOUNTS>(&instruction, &account_views[..len])?;
+                },
+            )
+        }
+    };
+
+    // NOTE: We assume the CPI names (CheckProgramId, CpiCtx, CpiData, CpiField,
+    // InstructionAccount, InstructionView, AccountView, Address, Result, and ProgramError with
+    // #[remaining]) are in scope.
+    Ok(quote! {
+        impl CheckProgramId for #struct_name<'_> {
+            const IDS: &'static [Address] = #programs;
+        }
+
+        impl<#info_lt> #struct_name<#info_lt> {
+            #account_methods
+
+            /// Instruction data: the discriminator followed by the arguments.
+            #[inline(always)]
+            pub fn instruction_data(
+                #(#arg_idents: #arg_tys,)*
+            ) -> CpiData<{ (#discriminator).len() #(+ <#size_tys as CpiField>::MAX_SIZE)* }> {
+                let mut ix_data = CpiData::new();
+                ix_data.write_bytes(&#discriminator);
+                #(ix_data.write(&#arg_idents);)*
+
+                ix_data
+            }
+        }
+
+        #[doc = #doc]
+        #[inline(always)]
+        #vis fn #fn_name<#info_lt>(
+            cpi_ctx: CpiCtx<#info_lt, '_, '_, '_, #struct_name<#info_lt>>,
+            #(#params,)*
+        ) -> Result<#fn_ret> {
+            #(#computed)*
+
+            let ix_data = #struct_name::instruction_data(#(#arg_idents),*);
+            #invoke_cpi
+
+            #ret_value
+        }
+    })
+}
+
+/// Replaces every lifetime with `'_`.
+struct ElideLifetimes;
+
+impl VisitMut for ElideLifetimes {
+    fn visit_lifetime_mut(&mut self, lifetime: &mut Lifetime) {
+        *lifetime = Lifetime::new("'_", Span::call_site());
+    }
+}
@@ -0,0 +1,166 @@
+// Copyright (c) 2026, Arcane Labs <dev@arcane.fi>
+// SPDX-License-Identifier: Apache-2.0
+
+use core::{mem::MaybeUninit, slice::from_raw_parts};
+use hayabusa_utility::{write_uninit_bytes, UNINIT_BYTE};
+use solana_address::Address;
+
+/// Argument of a CPI instruction, encoded the way native programs read it.
+///
+/// Integers are little-endian, `bool` is one byte and `Option<T>` is a one-byte tag followed by
+/// `T` only when present, so a trailing `None` shortens the data (`COption` layout used by the
+/// token programs).
+pub trait CpiField {
+    /// Maximum number of bytes the field encodes to.
+    const MAX_SIZE: usize;
+
+    /// Writes the field at the start of `bytes`, returning the number of bytes written.
+    fn write_cpi_field(&self, bytes: &mut [MaybeUninit<u8>]) -> usize;
+}
+
+macro_rules! impl_cpi_field_int {
+    ($($t:ty),* $(,)?) => {
+        $(
+            impl CpiField for $t {
+                const MAX_SIZE: usize = core::mem::size_of::<$t>();
+
+                #[inline(always)]
+                fn write_cpi_field(&self, bytes: &mut [MaybeUninit<u8>]) -> usize {
+                    write_uninit_bytes(bytes, &self.to_le_bytes());
+                    Self::MAX_SIZE
+                }
+            }
+        )*
+    };
+}
+
+impl_cpi_field_int!(u8, u16, u32, u64, u128, i8, i16, i32, i64, i128);
+
+impl CpiField for bool {
+    const MAX_SIZE: usize = 1;
+
+    #[inline(always)]
+    fn write_cpi_field(&self, bytes: &mut [MaybeUninit<u8>]) -> usize {
+        (*self as u8).write_cpi_field(bytes)
+    }
+}
+
+impl CpiField for Address {
+    const MAX_SIZE: usize = 32;
+
+    #[inline(always)]
+    fn write_cpi_field(&self, bytes: &mut [MaybeUninit<u8>]) -> usize {
+        write_uninit_bytes(bytes, self.as_ref());
+        Self::MAX_SIZE
+    }
+}
+
+impl<const N: usize> CpiField for [u8; N] {
+    const MAX_SIZE: usize = N;
+
+    #[inline(always)]
+    fn write_cpi_field(&self, bytes: &mut [MaybeUninit<u8>]) -> usize {
+        write_uninit_bytes(bytes, self);
+        N
+    }
+}
+
+impl<T: CpiField> CpiField for &T {
+    const MAX_SIZE: usize = T::MAX_SIZE;
+
+    #[inline(always)]
+    fn write_cpi_field(&self, bytes: &mut [MaybeUninit<u8>]) -> usize {
+        (**self).write_cpi_field(bytes)
+    }
+}
+
+impl<T: CpiField> CpiField for Option<T> {
+    const MAX_SIZE: usize = 1 + T::MAX_SIZE;
+
+    #[inline(always)]
+    fn write_cpi_field(&self, bytes: &mut [MaybeUninit<u8>]) -> usize {
+        match self {
+            Some(value) => {
+                write_uninit_bytes(bytes, &[1]);
+                1 + value.write_cpi_field(&mut bytes[1..])
+            }
+            None => {
+                write_uninit_bytes(bytes, &[0]);
+                1
+            }
+        }
+    }
+}
+
+/// Stack buffer CPI instruction data is assembled in, `N` being its maximum length.
+pub struct CpiData<const N: usize> {
+    bytes: [MaybeUninit<u8>; N],
+    len: usize,
+}
+
+impl<const N: usize> CpiData<N> {
+    #[inline(always)]
+    pub const fn new() -> Self {
+        Self {
+            bytes: [UNINIT_BYTE; N],
+            len: 0,
+        }
+    }
+
+    /// Appends raw bytes, e.g. the instruction discriminator.
+    #[inline(always)]
+    pub fn write_bytes(&mut self, bytes: &[u8]) {
+        write_uninit_bytes(&mut self.bytes[self.len..self.len + bytes.len()], bytes);
+        self.len += bytes.len();
+    }
+
+    /// Appends an encoded field.
+    #[inline(always)]
+    pub fn write<F: CpiField>(&mut self, field: &F) {
+        self.len += field.write_cpi_field(&mut self.bytes[self.len..]);
+    }
+
+    /// The bytes written so far.
+    #[inline(always)]
+    pub fn as_slice(&self) -> &[u8] {
+        // SAFETY: the first `len` bytes have been written
+        unsafe { from_raw_parts(self.bytes.as_ptr() as *const u8, self.len) }
+    }
+}
+
+impl<const N: usize> Default for CpiData<N> {
+    #[inline(always)]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn encodes_fields_in_order() {
+        let authority = Address::new_from_array([7; 32]);
+
+        let mut data = CpiData::<
+            { 1 + <u64 as CpiField>::MAX_SIZE + <Option<&Address> as CpiField>::MAX_SIZE },
+        >::new();
+        data.write_bytes(&[20]);
+        data.write(&0x0102u64);
+        data.write(&Some(&authority));
+
+        let mut expected = [0u8; 42];
+        expected[0] = 20;
+        expected[1..3].copy_from_slice(&[2, 1]);
+        expected[9] = 1;
+        expected[10..].copy_from_slice(authority.as_ref());
+        assert_eq!(data.as_slice(), &expected);
+
+        // a trailing `None` is a lone tag
+        let mut data = CpiData::<{ <Option<Address> as CpiField>::MAX_SIZE + 1 }>::new();
+        data.write(&None::<Address>);
+        data.write(&true);
+        assert_eq!(data.as_slice(), &[0, 1]);
+    }
+}
@@ -3,40 +3,50 @@
 
 #![no_std]
 
-use hayabusa_errors::Result;
+mod data;
+
+pub use data::*;
+
+use bytemuck::Pod;
+use hayabusa_errors::{ErrorCode, Result};
 use hayabusa_utility::{error_msg, hint::unlikely};
 use solana_account_view::AccountView;
 use solana_address::Address;
-use solana_instruction_view::cpi::Signer;
+use solana_instruction_view::{
+    cpi::{invoke, invoke_signed, invoke_signed_with_bounds, Signer},
+    InstructionView,
+};
 use solana_program_error::ProgramError;
 
 /// Trait for types that can validate a program ID.
 ///
-/// Implement this trait to associate a program address with a type and enable
-/// compile-time program ID validation in CPI contexts.
+/// Implement this trait to associate the programs accepting an instruction with its account
+/// type and enable program ID validation in CPI contexts. Instructions shared by several
+/// programs (e.g. Token and Token-2022) list each of them, the context invoking whichever
+/// one it was built with.
 ///
 /// # Example
 /// ```ignore
 /// pub struct SystemProgram;
 /// 
 /// impl CheckProgramId for SystemProgram {
-///     const ID: Address = system_program::ID;
+///     const IDS: &'static [Address] = &[system_program::ID];
 /// }
 /// ```
 pub trait CheckProgramId {
-    /// The expected program address.
-    const ID: Address;
+    /// The accepted program addresses.
+    const IDS: &'static [Address];
 
-    /// Validates that the provided address matches the expected program ID.
+    /// Validates that the provided address is one of the accepted program IDs.
     ///
     /// # Arguments
     /// - `id`: The program address to validate
     ///
     /// # Errors
-    /// Returns `ProgramError::IncorrectProgramId` if the address doesn't match.
+    /// Returns `ProgramError::IncorrectProgramId` if the address isn't accepted.
     #[inline(always)]
     fn check_program_id(id: &Address) -> Result<()> {
-        if unlikely(id != &Self::ID) {
+        if unlikely(!Self::IDS.contains(id)) {
             error_msg!(
                 "check_program_id: incorrect program id.",
                 ProgramError::IncorrectProgramId,
@@ -105,7 +115,7 @@ pub struct CpiCtx<'ix, 'a, 'b, 'c, T: CheckProgramId> {
 impl<'ix, 'a, 'b, 'c, T: CheckProgramId> CpiCtx<'ix, 'a, 'b, 'c, T> {
     /// Creates a new CPI context with optional signers.
     ///
-    /// Validates that `program` matches `T::ID` before constructing the context.
+    /// Validates that `program` is one of `T::IDS` before constructing the context.
     ///
     /// # Arguments
     /// - `program`: The program account being invoked
@@ -113,7 +123,7 @@ impl<'ix, 'a, 'b, 'c, T: CheckProgramId> CpiCtx<'ix, 'a, 'b, 'c, T> {
     /// - `signers`: Optional PDA signers (use `None` for non-PDA instructions)
     ///
     /// # Errors
-    /// Returns `ProgramError::IncorrectProgramId` if program address doesn't match `T::ID`.
+    /// Returns `ProgramError::IncorrectProgramId` if program address isn't in `T::IDS`.
     #[inline(always)]
     pub fn try_new(
         program: &'ix AccountView,
@@ -194,6 +204,83 @@ impl<'ix, 'a, 'b, 'c, T: CheckProgramId> CpiCtx<'ix, 'a, 'b, 'c, T> {
     }
 }
 
+impl<T: CheckProgramId> CpiCtx<'_, '_, '_, '_, T> {
+    /// Invokes `instruction`, signed by the context's signers if any.
+    #[inline(always)]
+    pub fn invoke<const ACCOUNTS: usize>(
+        &self,
+        instruction: &InstructionView,
+        account_views: &[&AccountView; ACCOUNTS],
+    ) -> Result<()> {
+        if let Some(signers) = self.signers {
+            invoke_signed(instruction, account_views, signers)
+        } else {
+            invoke(instruction, account_views)
+        }
+    }
+
+    /// Invokes `instruction` from a slice of account views, with room for up to `MAX_ACCOUNTS`
+    /// accounts on the stack.
+    #[inline(always)]
+    pub fn invoke_with_bounds<const MAX_ACCOUNTS: usize>(
+        &self,
+        instruction: &InstructionView,
+        account_views: &[&AccountView],
+    ) -> Result<()> {
+        invoke_signed_with_bounds::<MAX_ACCOUNTS>(
+            instruction,
+            account_views,
+            self.signers.unwrap_or(&[]),
+        )
+    }
+
+    /// Invokes `instruction` with the context's signers and reads the callee's return data
+    /// back as a `R`.
+    ///
+    /// # Errors
+    /// Returns `ErrorCode::InvalidReturnData` if the return data was not set by
+    /// `instruction.program_id` or its length differs from `size_of::<R>()`.
+    #[inline(always)]
+    pub fn invoke_with_return<R: Pod, const ACCOUNTS: usize>(
+        &self,
+        instruction: &InstructionView,
+        account_views: &[&AccountView; ACCOUNTS],
+    ) -> Result<R> {
+        self.invoke(instruction, account_views)?;
+
+        read_return_value(instruction.program_id)
+    }
+
+    /// Reads the return data the context's program set as a `R`, after an invocation.
+    ///
+    /// # Errors
+    /// Same as [`invoke_with_return`](Self::invoke_with_return).
+    #[inline(always)]
+    pub fn return_value<R: Pod>(&self) -> Result<R> {
+        read_return_value(self.program.address())
+    }
+}
+
+#[inline(always)]
+fn read_return_value<R: Pod>(program_id: &Address) -> Result<R> {
+    let mut value = R::zeroed();
+    let returned = hayabusa_syscalls::get_return_data(bytemuck::bytes_of_mut(&mut value));
+
+    match returned {
+        Some((returned_by, len))
+            if returned_by == *program_id && len == core::mem::size_of::<R>() =>
+        {
+            Ok(value)
+        }
+        _ => {
+            error_msg!(
+                "read_return_value: invalid return data.",
+                ErrorCode::InvalidReturnData,
+            );
+        }
+    }
+}
+
 /// Allows accessing the account struct directly through the context.
 ///
 /// # Example
@@ -211,4 +298,22 @@ impl<T: CheckProgramId> core::ops::Deref for CpiCtx<'_, '_, '_, '_, T> {
     fn deref(&self) -> &Self::Target {
         &self.accounts
     }
-}
\ No newline at end of file
+}
+
+/// Values a handler can return, written as the instruction's return data.
+///
+/// Implemented for every `Pod` type; `dispatch!` calls it on the handler's `Ok` value. Zero-sized
+/// values (including `()`) leave the return data untouched.
+pub trait ReturnValue {
+    /// Sets `self` as the running program's return data.
+    fn set_return_data(&self);
+}
+
+impl<T: Pod> ReturnValue for T {
+    #[inline(always)]
+    fn set_return_data(&self) {
+        if core::mem::size_of::<T>() != 0 {
+            hayabusa_syscalls::set_return_data(bytemuck::bytes_of(self));
+        }
+    }
+}
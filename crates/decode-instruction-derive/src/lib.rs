@@ -5,11 +5,12 @@ use proc_macro::TokenStream;
 use proc_macro2::{Span, TokenStream as TokenStream2};
 use quote::{quote, ToTokens};
 use syn::{
-    parse_macro_input, spanned::Spanned, Data, DeriveInput, Error, Fields, GenericParam,
-    Generics, Ident, Lifetime, LifetimeParam, Type, TypeArray, TypePath, TypeReference, TypeSlice,
+    parse_macro_input, spanned::Spanned, Data, DeriveInput, Error, Expr, ExprLit, Fields,
+    GenericParam, Generics, Ident, Lifetime, LifetimeParam, Lit, Type, TypePath, TypeReference,
+    TypeSlice,
 };
 
-#[proc_macro_derive(DecodeIx)]
+#[proc_macro_derive(DecodeIx, attributes(prefix, decode))]
 pub fn derive_decode_ix(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
     match expand_decode_ix(input) {
@@ -18,6 +19,205 @@ pub fn derive_decode_ix(input: TokenStream) -> TokenStream {
     }
 }
 
+/// Derives `IxField` for fieldless enums (one discriminant byte) and structs with named
+/// fields (fields back to back, each through its own `IxField` impl).
+///
+/// Also derives `BorshIxField`, so the type can be used with `#[decode(borsh)]`. Borsh
+/// encodes enums by variant index rather than discriminant value.
+#[proc_macro_derive(IxField)]
+pub fn derive_ix_field(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    match expand_ix_field(input) {
+        Ok(ts) => ts.into(),
+        Err(e) => e.to_compile_error().into(),
+    }
+}
+
+fn expand_ix_field(input: DeriveInput) -> Result<TokenStream2, Error> {
+    let ident = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let (size, decode, encode, borsh) = match &input.data {
+        Data::Enum(e) => {
+            if e.variants.len() > 256 {
+                return Err(Error::new(
+                    ident.span(),
+                    "IxField derive supports at most 256 enum variants",
+                ));
+            }
+
+            // without `#[repr(u8)]`, discriminants above 255 would truncate onto other variants
+            let repr_u8 = input.attrs.iter().any(|a| {
+                a.path().is_ident("repr") && a.parse_args::<Ident>().is_ok_and(|r| r == "u8")
+            });
+
+            let mut variants = Vec::new();
+            let mut discriminant = 0u64;
+            for variant in &e.variants {
+                if !matches!(variant.fields, Fields::Unit) {
+                    return Err(Error::new(
+                        variant.span(),
+                        "IxField derive only supports fieldless enum variants",
+                    ));
+                }
+
+                if !repr_u8 {
+                    if let Some((_, expr)) = &variant.discriminant {
+                        discriminant = match expr {
+                            Expr::Lit(ExprLit { lit: Lit::Int(int), .. }) => int.base10_parse()?,
+                            _ => {
+                                return Err(Error::new(
+                                    expr.span(),
+                                    "IxField derive requires #[repr(u8)] for non-literal \
+                                     discriminants",
+                                ))
+                            }
+                        };
+                    }
+
+                    if discriminant > u8::MAX as u64 {
+                        return Err(Error::new(
+                            variant.span(),
+                            "IxField enum discriminants must fit in a u8",
+                        ));
+                    }
+                    discriminant += 1;
+                }
+
+                variants.push(&variant.ident);
+            }
+
+            (
+                quote!(1usize),
+                quote! {
+                    match bytes[0] {
+                        #(tag if tag == Self::#variants as u8 => Ok(Self::#variants),)*
+                        _ => Err(ProgramError::InvalidInstructionData),
+                    }
+                },
+                quote! {
+                    bytes[0] = match self {
+                        #(Self::#variants => Self::#variants as u8,)*
+                    };
+                },
+                {
+                    let indices = (0..variants.len()).map(|i| i as u8);
+                    let indices2 = indices.clone();
+
+                    (
+                        quote! {
+                            match <u8 as BorshIxField>::decode_borsh(bytes, offset)? {
+                                #(#indices => Ok(Self::#variants),)*
+                                _ => Err(ProgramError::InvalidInstructionData),
+                            }
+                        },
+                        quote!(1usize),
+                        quote! {
+                            bytes[0] = match self {
+                                #(Self::#variants => #indices2,)*
+                            };
+                        },
+                    )
+                },
+            )
+        }
+        Data::Struct(s) => {
+            let Fields::Named(named) = &s.fields else {
+                return Err(Error::new(
+                    s.fields.span(),
+                    "IxField derive only supports structs with named fields",
+                ));
+            };
+
+            let names: Vec<_> = named.named.iter().map(|f| f.ident.clone().unwrap()).collect();
+            let tys: Vec<_> = named.named.iter().map(|f| f.ty.clone()).collect();
+
+            (
+                quote!(0usize #(+ <#tys as IxField>::SIZE)*),
+                quote! {
+                    let mut __off: usize = 0usize;
+                    #(
+                        let #names = <#tys as IxField>::decode_field(
+                            &bytes[__off..__off + <#tys as IxField>::SIZE],
+                        )?;
+                        __off += <#tys as IxField>::SIZE;
+                    )*
+
+                    Ok(Self { #(#names),* })
+                },
+                quote! {
+                    let mut __off: usize = 0usize;
+                    #(
+                        self.#names.encode_field(&mut bytes[__off..__off + <#tys as IxField>::SIZE]);
+                        __off += <#tys as IxField>::SIZE;
+                    )*
+                },
+                (
+                    quote! {
+                        Ok(Self {
+                            #(#names: BorshIxField::decode_borsh(bytes, offset)?,)*
+                        })
+                    },
+                    quote!(0usize #(+ BorshIxField::borsh_len(&self.#names))*),
+                    quote! {
+                        let mut __off: usize = 0usize;
+                        #(
+                            let __len = BorshIxField::borsh_len(&self.#names);
+                            BorshIxField::encode_borsh(&self.#names, &mut bytes[__off..__off + __len]);
+                            __off += __len;
+                        )*
+                    },
+                ),
+            )
+        }
+        Data::Union(_) => {
+            return Err(Error::new(
+                ident.span(),
+                "IxField derive only supports enums and structs",
+            ))
+        }
+    };
+
+    let (borsh_decode, borsh_len, borsh_encode) = borsh;
+    let (borsh_lt, borsh_impl_generics, _, _, _) = lifetime_strategy(&input.generics)?;
+
+    Ok(quote! {
+        impl #borsh_impl_generics BorshIxField<#borsh_lt> for #ident #ty_generics #where_clause {
+            #[inline(always)]
+            fn decode_borsh(bytes: &#borsh_lt [u8], offset: &mut usize) -> Result<Self> {
+                #borsh_decode
+            }
+
+            #[inline(always)]
+            fn borsh_len(&self) -> usize {
+                #borsh_len
+            }
+
+            #[inline(always)]
+            #[allow(unused_assignments)]
+            fn encode_borsh(&self, bytes: &mut [u8]) {
+                #borsh_encode
+            }
+        }
+
+        impl #impl_generics IxField for #ident #ty_generics #where_clause {
+            const SIZE: usize = #size;
+
+            #[inline(always)]
+            #[allow(unused_assignments)]
+            fn decode_field(bytes: &[u8]) -> Result<Self> {
+                #decode
+            }
+
+            #[inline(always)]
+            #[allow(unused_assignments)]
+            fn encode_field(&self, bytes: &mut [u8]) {
+                #encode
+            }
+        }
+    })
+}
+
 fn expand_decode_ix(input: DeriveInput) -> Result<TokenStream2, Error> {
     let ident = input.ident;
 
@@ -43,109 +243,139 @@ fn expand_decode_ix(input: DeriveInput) -> Result<TokenStream2, Error> {
         _ => return Err(Error::new(Span::call_site(), "DecodeIx derive only supports structs")),
     };
 
+    let borsh = parse_decode_mode(&input.attrs)?;
+
     // Decide the lifetime used for DecodeIx<'ix>:
     // - If the type has at least one lifetime parameter, use the first one (whatever its name).
     // - Otherwise, introduce a fresh 'ix only in the impl generics (type stays non-generic).
     let (ix_lt, impl_generics_ts, ty_generics_ts, where_clause_ts, type_has_lifetime) =
         lifetime_strategy(&input.generics)?;
 
+    if borsh {
+        return expand_decode_ix_borsh(
+            &ident,
+            &fields,
+            &ix_lt,
+            &impl_generics_ts,
+            &ty_generics_ts,
+            &where_clause_ts,
+        );
+    }
+
     // Scan fields, classify the single borrowed byte slice (if present)
     let mut slice_field: Option<(Ident, Type)> = None;
+    let mut has_var_field = false;
 
-    // We'll generate two decode passes:
-    // 1) decode all fixed-size fields up to the slice, skipping slice
-    // 2) compute slice_len = bytes.len() - fixed_total, decode slice at its position,
-    //    and continue decoding remaining fixed-size fields.
+    // Fields are decoded in order with a running offset:
+    // - fixed-size fields are covered by the up-front minimum length check
+    // - length-prefixed fields read their prefix, then bounds-check their content against
+    //   the minimum size of the fields that follow
+    // - the unprefixed slice takes whatever the fields after it don't need
     //
-    // To do that, we record per-field decode "ops" in order, with a marker for the slice.
+    // To do that, we record per-field decode "ops" in order, each with its minimum size.
     enum Op {
-        Fixed { ty: Type, size: TokenStream2, decode: TokenStream2, init: TokenStream2 },
+        Fixed { size: TokenStream2, decode: TokenStream2, init: TokenStream2 },
         Slice { name: Ident, ty: Type },
+        Var { name: Ident, ty: Box<Type>, prefix: Box<Type> },
     }
 
     let mut ops: Vec<Op> = Vec::new();
-    let mut fixed_sizes: Vec<TokenStream2> = Vec::new();
+    let mut min_sizes: Vec<TokenStream2> = Vec::new();
 
     for field in fields.iter() {
         let name = field.ident.clone().unwrap();
         let ty = field.ty.clone();
+        let prefix = parse_prefix(&field.attrs)?;
 
         // Allow PhantomData to be initialized without consuming bytes.
         if is_phantom_data(&ty) {
             ops.push(Op::Fixed {
-                ty: ty.clone(),
                 size: quote!(0usize),
                 decode: quote! { let #name: #ty = core::marker::PhantomData; },
                 init: quote!(#name: #name),
             });
+            min_sizes.push(quote!(0usize));
             continue;
         }
 
-        // Borrowed slices:
-        if is_any_slice_ref(&ty) {
-            // Only allow &'ix [u8] (alignment-safe)
-            if !is_u8_slice_ref(&ty) {
-                return Err(Error::new(
-                    ty.span(),
-                    "DecodeIx derive only supports borrowed byte slices: &'ix [u8]. Borrowed &[T] is not safe on Solana because instruction data is only 1-byte aligned.",
-                ));
-            }
+        let is_slice = is_any_slice_ref(&ty);
+        let is_var = is_str_ref(&ty) || is_pod_slice(&ty) || (is_slice && prefix.is_some());
 
-            // If the type has no lifetime params, it cannot contain &'ix [u8].
-            if !type_has_lifetime {
-                return Err(Error::new(
-                    ty.span(),
-                    "Struct contains a borrowed field (&'ix [u8]) but declares no lifetime parameter. Add a lifetime, e.g. `pub struct FooIx<'ix> { data: &'ix [u8], ... }`.",
-                ));
-            }
+        if (is_slice || is_var) && !type_has_lifetime {
+            // If the type has no lifetime params, it cannot contain borrowed fields.
+            return Err(Error::new(
+                ty.span(),
+                "Struct contains a borrowed field (e.g. &'ix [u8]) but declares no lifetime parameter. Add a lifetime, e.g. `pub struct FooIx<'ix> { data: &'ix [u8], ... }`.",
+            ));
+        }
 
-            // Only one slice field allowed (unambiguous remainder)
-            if slice_field.is_some() {
+        // Only allow &'ix [u8] (alignment-safe)
+        if is_slice && !is_u8_slice_ref(&ty) {
+            return Err(Error::new(
+                ty.span(),
+                "DecodeIx derive only supports borrowed byte slices: &'ix [u8]. Borrowed &[T] is not safe on Solana because instruction data is only 1-byte aligned; use PodSlice<'ix, T> instead.",
+            ));
+        }
+
+        if let Some((slice_name, _)) = &slice_field {
+            if is_slice || is_var {
                 return Err(Error::new(
                     ty.span(),
-                    "DecodeIx derive supports at most one borrowed byte slice (&'ix [u8]) because its length is derived as the remainder of the input.",
+                    format!("`{slice_name}` takes the remainder of the input, so no variable-length field may follow it. Give it a length prefix, e.g. `#[prefix(u16)]`."),
                 ));
             }
+        }
+
+        if is_var {
+            let prefix = prefix.unwrap_or_else(|| syn::parse_quote!(u32));
+
+            has_var_field = true;
+            min_sizes.push(quote!(<#prefix as IxPrefix>::SIZE));
+            ops.push(Op::Var { name, ty: Box::new(ty), prefix: Box::new(prefix) });
+            continue;
+        }
 
+        if prefix.is_some() {
+            return Err(Error::new(
+                ty.span(),
+                "#[prefix(..)] only applies to &'ix [u8], &'ix str and PodSlice<'ix, T> fields",
+            ));
+        }
+
+        // Borrowed remainder slice
+        if is_slice {
             slice_field = Some((name.clone(), ty.clone()));
+            min_sizes.push(quote!(0usize));
             ops.push(Op::Slice { name, ty });
             continue;
         }
 
         // Fixed-size fields
-        let size_expr = size_of_type_expr(&ty)?;
+        let size_expr = size_of_type_expr(&ty);
         let decode_stmt = decode_fixed_field_stmt(&name, &ty);
 
-        fixed_sizes.push(size_expr.clone());
+        min_sizes.push(size_expr.clone());
         ops.push(Op::Fixed {
-            ty: ty.clone(),
             size: size_expr,
             decode: decode_stmt,
             init: quote!(#name: #name),
         });
     }
 
-    let fixed_total = if fixed_sizes.is_empty() {
-        quote!(0usize)
-    } else {
-        fixed_sizes
-            .into_iter()
-            .reduce(|a, b| quote!((#a) + (#b)))
-            .unwrap()
-    };
+    let min_total = sum_sizes(&min_sizes);
 
     // Bounds check:
-    // - If slice exists: bytes.len() >= fixed_total
-    // - Else: bytes.len() == fixed_total
-    let len_check = if slice_field.is_some() {
+    // - If slice or length-prefixed fields exist: bytes.len() >= min_total
+    // - Else: bytes.len() == min_total
+    let len_check = if slice_field.is_some() || has_var_field {
         quote! {
-            if bytes.len() < #fixed_total {
+            if bytes.len() < #min_total {
                 return Err(ProgramError::InvalidInstructionData);
             }
         }
     } else {
         quote! {
-            if bytes.len() != #fixed_total {
+            if bytes.len() != #min_total {
                 return Err(ProgramError::InvalidInstructionData);
             }
         }
@@ -158,28 +388,62 @@ fn expand_decode_ix(input: DeriveInput) -> Result<TokenStream2, Error> {
 
     decode_stmts.push(quote! { let mut #off: usize = 0usize; });
 
-    for op in ops {
+    for (i, op) in ops.into_iter().enumerate() {
+        // minimum size of the fields after this one
+        let rest = sum_sizes(&min_sizes[i + 1..]);
+
         match op {
-            Op::Fixed { ty: _ty, size, decode, init, .. } => {
+            Op::Fixed { size, decode, init } => {
                 // For PhantomData we used size=0 and a direct let.
                 decode_stmts.push(decode);
-                // If size is 0usize, still ok to add; but avoid useless add for tidiness
                 decode_stmts.push(quote! { #off += #size; });
                 inits.push(init);
             }
             Op::Slice { name, ty } => {
-                // Remainder slice: slice_len = bytes.len() - fixed_total
+                // Remainder slice: everything the following fixed fields don't need.
                 // We place it exactly at current offset.
                 decode_stmts.push(quote! {
-                    let __slice_len: usize = bytes.len() - #fixed_total;
+                    let __slice_len: usize = bytes.len() - #off - #rest;
                     let #name: #ty = &bytes[#off .. #off + __slice_len];
                     #off += __slice_len;
                 });
                 inits.push(quote!(#name: #name));
             }
+            Op::Var { name, ty, prefix } => {
+                decode_stmts.push(quote! {
+                    // SAFETY: covered by the previous length check
+                    let __count: usize = <#prefix as IxPrefix>::decode_len(unsafe {
+                        bytes.get_unchecked(#off..#off + <#prefix as IxPrefix>::SIZE)
+                    });
+                    #off += <#prefix as IxPrefix>::SIZE;
+
+                    let __size: usize = __count
+                        .checked_mul(<#ty as IxVarField<#ix_lt>>::ELEM_SIZE)
+                        .ok_or(ProgramError::InvalidInstructionData)?;
+                    if bytes.len() - #off - #rest < __size {
+                        return Err(ProgramError::InvalidInstructionData);
+                    }
+
+                    // SAFETY: bounds checked above
+                    let #name: #ty = <#ty as IxVarField<#ix_lt>>::from_content(unsafe {
+                        bytes.get_unchecked(#off..#off + __size)
+                    })?;
+                    #off += __size;
+                });
+                inits.push(quote!(#name: #name));
+            }
         }
     }
 
+    // Length-prefixed fields consume an exact amount, so reject trailing bytes.
+    if has_var_field && slice_field.is_none() {
+        decode_stmts.push(quote! {
+            if #off != bytes.len() {
+                return Err(ProgramError::InvalidInstructionData);
+            }
+        });
+    }
+
     // Remove the extra "off += size" for PhantomData (since size=0 it's harmless, but we can keep it).
     // Note: This macro assumes `Result` and `ProgramError` are in scope in the target crate.
     let expanded = quote! {
@@ -199,6 +463,73 @@ fn expand_decode_ix(input: DeriveInput) -> Result<TokenStream2, Error> {
     Ok(expanded)
 }
 
+/// Borsh layout: every field is read with a running offset through `BorshIxField`, and
+/// borrowed `&'ix [u8]`/`&'ix str` fields are `u32`-prefixed like Borsh's `Vec<u8>`/`String`.
+fn expand_decode_ix_borsh(
+    ident: &Ident,
+    fields: &syn::punctuated::Punctuated<syn::Field, syn::Token![,]>,
+    ix_lt: &Lifetime,
+    impl_generics_ts: &TokenStream2,
+    ty_generics_ts: &TokenStream2,
+    where_clause_ts: &TokenStream2,
+) -> Result<TokenStream2, Error> {
+    let mut decode_stmts = Vec::new();
+    let mut inits = Vec::new();
+
+    for field in fields.iter() {
+        let name = field.ident.clone().unwrap();
+        let ty = &field.ty;
+
+        if parse_prefix(&field.attrs)?.is_some() {
+            return Err(Error::new(
+                ty.span(),
+                "#[prefix(..)] is not supported with #[decode(borsh)]; Borsh always uses a u32 length prefix",
+            ));
+        }
+
+        if is_phantom_data(ty) {
+            decode_stmts.push(quote! { let #name: #ty = core::marker::PhantomData; });
+        } else {
+            decode_stmts.push(quote! {
+                let #name: #ty = <#ty as BorshIxField<#ix_lt>>::decode_borsh(bytes, &mut __off)?;
+            });
+        }
+        inits.push(quote!(#name: #name));
+    }
+
+    Ok(quote! {
+        impl #impl_generics_ts DecodeIx<#ix_lt> for #ident #ty_generics_ts #where_clause_ts {
+            #[inline(always)]
+            fn decode(bytes: &#ix_lt [u8]) -> Result<Self> {
+                let mut __off: usize = 0usize;
+                #(#decode_stmts)*
+
+                if __off != bytes.len() {
+                    return Err(ProgramError::InvalidInstructionData);
+                }
+
+                Ok(Self {
+                    #(#inits),*
+                })
+            }
+        }
+    })
+}
+
+/// Parses `#[decode(borsh)]`; returns whether the Borsh layout was requested.
+fn parse_decode_mode(attrs: &[syn::Attribute]) -> Result<bool, Error> {
+    let Some(attr) = attrs.iter().find(|a| a.path().is_ident("decode")) else {
+        return Ok(false);
+    };
+
+    let mode: Ident = attr.parse_args()?;
+    if mode != "borsh" {
+        return Err(Error::new(mode.span(), "expected `#[decode(borsh)]`"));
+    }
+
+    Ok(true)
+}
+
 fn has_repr_c(attrs: &[syn::Attribute]) -> bool {
     attrs.iter().any(|a| {
         if !a.path().is_ident("repr") {
@@ -279,33 +610,48 @@ fn is_u8_slice_ref(ty: &Type) -> bool {
     )
 }
 
-fn size_of_type_expr(ty: &Type) -> Result<TokenStream2, Error> {
-    // Only allow [u8; N] arrays (fixed, safe)
-    if let Type::Array(TypeArray { elem, .. }) = ty {
-        if !is_u8_type(elem.as_ref()) {
-            return Err(Error::new(
-                ty.span(),
-                "DecodeIx derive only supports fixed arrays of bytes: [u8; N]",
-            ));
-        }
+fn sum_sizes(sizes: &[TokenStream2]) -> TokenStream2 {
+    if sizes.is_empty() {
+        quote!(0usize)
+    } else {
+        quote!((0usize #(+ #sizes)*))
     }
+}
 
-    Ok(quote!(core::mem::size_of::<#ty>()))
+/// Parses `#[prefix(u8 | u16 | u32)]`.
+fn parse_prefix(attrs: &[syn::Attribute]) -> Result<Option<Type>, Error> {
+    attrs
+        .iter()
+        .find(|a| a.path().is_ident("prefix"))
+        .map(|a| a.parse_args::<Type>())
+        .transpose()
 }
 
-fn is_u8_type(ty: &Type) -> bool {
+fn is_str_ref(ty: &Type) -> bool {
     matches!(
         ty,
-        Type::Path(TypePath { qself: None, path, .. }) if path.is_ident("u8")
+        Type::Reference(TypeReference { elem, .. })
+            if matches!(elem.as_ref(), Type::Path(TypePath { qself: None, path, .. }) if path.is_ident("str"))
     )
 }
 
+fn is_pod_slice(ty: &Type) -> bool {
+    matches!(
+        ty,
+        Type::Path(TypePath { qself: None, path, .. })
+            if path.segments.last().is_some_and(|s| s.ident == "PodSlice")
+    )
+}
+
+fn size_of_type_expr(ty: &Type) -> TokenStream2 {
+    quote!(<#ty as IxField>::SIZE)
+}
+
 fn decode_fixed_field_stmt(name: &Ident, ty: &Type) -> TokenStream2 {
     quote! {
-        let #name: #ty = unsafe {
-            core::ptr::read_unaligned(
-                bytes.as_ptr().add(__off) as *const #ty
-            )
-        };
+        // SAFETY: the length check above covers every fixed field
+        let #name: #ty = <#ty as IxField>::decode_field(unsafe {
+            bytes.get_unchecked(__off..__off + <#ty as IxField>::SIZE)
+        })?;
     }
-}
\ No newline at end of file
+}
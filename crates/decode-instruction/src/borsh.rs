@@ -0,0 +1,328 @@
+// Copyright (c) 2026, Arcane Labs <dev@arcane.fi>
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{IxField, PodSlice};
+use bytemuck::Pod;
+use core::mem::MaybeUninit;
+use hayabusa_errors::{ProgramError, Result};
+use solana_address::Address;
+
+/// Trait for instruction arguments decoded from a Borsh layout, selected with
+/// `#[decode(borsh)]` on `DecodeIx` (or `#[program(borsh)]`).
+///
+/// Decoding never allocates: `Vec<u8>`, `String` and `Vec<T: Pod>` are borrowed from the
+/// instruction data as `&'ix [u8]`, `&'ix str` and [`PodSlice`].
+///
+/// # Encoding
+/// - integers: little-endian
+/// - `bool`: one byte, `0` or `1`
+/// - `Address`, `[T; N]`: elements back to back
+/// - `Option<T>`: a tag byte, followed by `T` only for `Some`
+/// - `&[u8]`, `&str`, `PodSlice<T>`: a `u32` element count followed by the elements
+/// - `#[derive(IxField)]` enums: the variant index as one byte
+/// - `#[derive(IxField)]` structs: fields back to back in declaration order
+pub trait BorshIxField<'ix>: Sized {
+    /// Decodes the field at `*offset`, advancing it past the consumed bytes.
+    ///
+    /// # Errors
+    /// Returns `ProgramError::InvalidInstructionData` if the data is too short or the bytes
+    /// are not a valid encoding.
+    fn decode_borsh(bytes: &'ix [u8], offset: &mut usize) -> Result<Self>;
+
+    /// Encoded size of this value in bytes.
+    fn borsh_len(&self) -> usize;
+
+    /// Encodes the value into exactly `self.borsh_len()` bytes.
+    fn encode_borsh(&self, bytes: &mut [u8]);
+}
+
+/// Takes the next `len` bytes at `*offset`, advancing it.
+#[inline(always)]
+pub fn take_borsh_bytes<'ix>(bytes: &'ix [u8], offset: &mut usize, len: usize) -> Result<&'ix [u8]> {
+    let end = offset
+        .checked_add(len)
+        .ok_or(ProgramError::InvalidInstructionData)?;
+    let taken = bytes
+        .get(*offset..end)
+        .ok_or(ProgramError::InvalidInstructionData)?;
+
+    *offset = end;
+
+    Ok(taken)
+}
+
+macro_rules! impl_borsh_ix_field_fixed {
+    ($($t:ty),*) => {
+        $(
+            impl<'ix> BorshIxField<'ix> for $t {
+                #[inline(always)]
+                fn decode_borsh(bytes: &'ix [u8], offset: &mut usize) -> Result<Self> {
+                    <$t as IxField>::decode_field(take_borsh_bytes(
+                        bytes,
+                        offset,
+                        <$t as IxField>::SIZE,
+                    )?)
+                }
+
+                #[inline(always)]
+                fn borsh_len(&self) -> usize {
+                    <$t as IxField>::SIZE
+                }
+
+                #[inline(always)]
+                fn encode_borsh(&self, bytes: &mut [u8]) {
+                    self.encode_field(bytes);
+                }
+            }
+        )*
+    };
+}
+
+impl_borsh_ix_field_fixed!(u8, u16, u32, u64, u128, i8, i16, i32, i64, i128, bool, Address);
+
+impl<'ix, T: BorshIxField<'ix>, const N: usize> BorshIxField<'ix> for [T; N] {
+    #[inline(always)]
+    fn decode_borsh(bytes: &'ix [u8], offset: &mut usize) -> Result<Self> {
+        let mut out = [const { MaybeUninit::<T>::uninit() }; N];
+
+        for slot in out.iter_mut() {
+            slot.write(T::decode_borsh(bytes, offset)?);
+        }
+
+        // SAFETY: every element was written above; MaybeUninit<T> has the layout of T
+        Ok(unsafe { (out.as_ptr() as *const [T; N]).read() })
+    }
+
+    #[inline(always)]
+    fn borsh_len(&self) -> usize {
+        self.iter().map(T::borsh_len).sum()
+    }
+
+    #[inline(always)]
+    fn encode_borsh(&self, bytes: &mut [u8]) {
+        let mut off = 0;
+
+        for item in self {
+            let len = item.borsh_len();
+            item.encode_borsh(&mut bytes[off..off + len]);
+            off += len;
+        }
+    }
+}
+
+impl<'ix, T: BorshIxField<'ix>> BorshIxField<'ix> for Option<T> {
+    #[inline(always)]
+    fn decode_borsh(bytes: &'ix [u8], offset: &mut usize) -> Result<Self> {
+        match take_borsh_bytes(bytes, offset, 1)?[0] {
+            0 => Ok(None),
+            1 => Ok(Some(T::decode_borsh(bytes, offset)?)),
+            _ => Err(ProgramError::InvalidInstructionData),
+        }
+    }
+
+    #[inline(always)]
+    fn borsh_len(&self) -> usize {
+        1 + self.as_ref().map_or(0, T::borsh_len)
+    }
+
+    #[inline(always)]
+    fn encode_borsh(&self, bytes: &mut [u8]) {
+        match self {
+            Some(value) => {
+                bytes[0] = 1;
+                value.encode_borsh(&mut bytes[1..]);
+            }
+            None => bytes[0] = 0,
+        }
+    }
+}
+
+/// Reads a `u32` element count and the `count * elem_size` bytes after it.
+#[inline(always)]
+fn take_borsh_vec<'ix>(bytes: &'ix [u8], offset: &mut usize, elem_size: usize) -> Result<&'ix [u8]> {
+    let count = u32::decode_borsh(bytes, offset)? as usize;
+    let len = count
+        .checked_mul(elem_size)
+        .ok_or(ProgramError::InvalidInstructionData)?;
+
+    take_borsh_bytes(bytes, offset, len)
+}
+
+/// Writes `count` as the `u32` prefix followed by `content`.
+///
+/// Borsh caps lengths at `u32::MAX`; instruction data never gets close, so larger counts are
+/// only caught in debug builds.
+#[inline(always)]
+fn encode_borsh_vec(count: usize, content: &[u8], bytes: &mut [u8]) {
+    debug_assert!(u32::try_from(count).is_ok(), "Borsh length exceeds u32::MAX");
+    (count as u32).encode_field(&mut bytes[..4]);
+    bytes[4..].copy_from_slice(content);
+}
+
+impl<'ix> BorshIxField<'ix> for &'ix [u8] {
+    #[inline(always)]
+    fn decode_borsh(bytes: &'ix [u8], offset: &mut usize) -> Result<Self> {
+        take_borsh_vec(bytes, offset, 1)
+    }
+
+    #[inline(always)]
+    fn borsh_len(&self) -> usize {
+        4 + self.len()
+    }
+
+    #[inline(always)]
+    fn encode_borsh(&self, bytes: &mut [u8]) {
+        encode_borsh_vec(self.len(), self, bytes);
+    }
+}
+
+impl<'ix> BorshIxField<'ix> for &'ix str {
+    #[inline(always)]
+    fn decode_borsh(bytes: &'ix [u8], offset: &mut usize) -> Result<Self> {
+        core::str::from_utf8(take_borsh_vec(bytes, offset, 1)?)
+            .map_err(|_| ProgramError::InvalidInstructionData)
+    }
+
+    #[inline(always)]
+    fn borsh_len(&self) -> usize {
+        4 + self.len()
+    }
+
+    #[inline(always)]
+    fn encode_borsh(&self, bytes: &mut [u8]) {
+        encode_borsh_vec(self.len(), self.as_bytes(), bytes);
+    }
+}
+
+impl<'ix, T: Pod> BorshIxField<'ix> for PodSlice<'ix, T> {
+    #[inline(always)]
+    fn decode_borsh(bytes: &'ix [u8], offset: &mut usize) -> Result<Self> {
+        let content = take_borsh_vec(bytes, offset, PodSlice::<T>::ELEM_LEN)?;
+
+        Ok(PodSlice::from_bytes(content))
+    }
+
+    #[inline(always)]
+    fn borsh_len(&self) -> usize {
+        4 + self.as_bytes().len()
+    }
+
+    #[inline(always)]
+    fn encode_borsh(&self, bytes: &mut [u8]) {
+        encode_borsh_vec(self.len(), self.as_bytes(), bytes);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::DecodeIx;
+    use hayabusa_decode_instruction_derive::{DecodeIx, IxField};
+
+    #[derive(IxField, Clone, Copy, Debug, PartialEq)]
+    enum Mode {
+        Off,
+        On = 7,
+    }
+
+    #[derive(DecodeIx)]
+    #[decode(borsh)]
+    #[repr(C)]
+    struct RenameIx<'ix> {
+        name: &'ix str,
+        limit: Option<u64>,
+        memo: &'ix [u8],
+        mode: Mode,
+        weights: PodSlice<'ix, u16>,
+    }
+
+    // laid out the way a Borsh client serializes `(String, Option<u64>, Vec<u8>, Mode, Vec<u16>)`
+    const RENAME: [u8; 35] = [
+        5, 0, 0, 0, b'h', b'e', b'l', b'l', b'o', // name
+        1, 7, 0, 0, 0, 0, 0, 0, 0, // limit
+        2, 0, 0, 0, 3, 4, // memo
+        1, // mode
+        2, 0, 0, 0, 9, 0, 0, 1, 0, 0, // weights, plus two trailing bytes
+    ];
+
+    #[test]
+    fn decode_ix_borsh() {
+        let ix = RenameIx::decode(&RENAME[..33]).unwrap();
+        assert_eq!(ix.name, "hello");
+        assert_eq!(ix.limit, Some(7));
+        assert_eq!(ix.memo, &[3, 4]);
+        // enums are encoded by variant index, not discriminant
+        assert_eq!(ix.mode, Mode::On);
+        assert_eq!(
+            (ix.weights.len(), ix.weights.get(0), ix.weights.get(1)),
+            (2, Some(9), Some(256))
+        );
+
+        // a `None` tag carries no payload
+        let none = [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0];
+        let ix = RenameIx::decode(&none).unwrap();
+        assert_eq!(
+            (ix.name, ix.limit, ix.memo, ix.mode),
+            ("", None, &[][..], Mode::Off)
+        );
+        assert!(ix.weights.is_empty());
+
+        let err = Some(ProgramError::InvalidInstructionData);
+        // truncated element, truncated length prefix, trailing bytes
+        assert_eq!(RenameIx::decode(&RENAME[..32]).err(), err);
+        assert_eq!(RenameIx::decode(&RENAME[..2]).err(), err);
+        assert_eq!(RenameIx::decode(&RENAME).err(), err);
+
+        // unknown variant index
+        let mut bytes = RENAME;
+        bytes[24] = 2;
+        assert_eq!(RenameIx::decode(&bytes[..33]).err(), err);
+
+        // invalid UTF-8
+        let mut bytes = RENAME;
+        bytes[4..6].copy_from_slice(&[0xc3, 0x28]);
+        assert_eq!(RenameIx::decode(&bytes[..33]).err(), err);
+    }
+
+    #[test]
+    fn borsh_vec_lengths() {
+        let err = Some(ProgramError::InvalidInstructionData);
+
+        // a count far beyond the data
+        let huge = u32::MAX.to_le_bytes();
+        assert_eq!(<&[u8]>::decode_borsh(&huge, &mut 0).err(), err);
+        assert_eq!(PodSlice::<u64>::decode_borsh(&huge, &mut 0).err(), err);
+
+        // `count * elem_size` overflowing usize
+        assert_eq!(take_borsh_vec(&[2, 0, 0, 0], &mut 0, usize::MAX).err(), err);
+
+        // a length prefix cut short
+        assert_eq!(<&str>::decode_borsh(&[1, 0, 0], &mut 0).err(), err);
+    }
+
+    #[test]
+    fn borsh_round_trip() {
+        let mut bytes = [0u8; 32];
+
+        let value: (&str, Option<i32>, [Mode; 2]) = ("hé", Some(-3), [Mode::On, Mode::Off]);
+        let len = value.0.borsh_len() + value.1.borsh_len() + value.2.borsh_len();
+        assert_eq!(len, 4 + 3 + 5 + 2);
+
+        value.0.encode_borsh(&mut bytes[..7]);
+        value.1.encode_borsh(&mut bytes[7..12]);
+        value.2.encode_borsh(&mut bytes[12..14]);
+        assert_eq!(
+            bytes[..14],
+            [3, 0, 0, 0, b'h', 0xc3, 0xa9, 1, 0xfd, 0xff, 0xff, 0xff, 1, 0]
+        );
+
+        let offset = &mut 0;
+        assert_eq!(<&str>::decode_borsh(&bytes, offset), Ok("hé"));
+        assert_eq!(<Option<i32>>::decode_borsh(&bytes, offset), Ok(Some(-3)));
+        assert_eq!(
+            <[Mode; 2]>::decode_borsh(&bytes, offset),
+            Ok([Mode::On, Mode::Off])
+        );
+        assert_eq!(*offset, len);
+    }
+}
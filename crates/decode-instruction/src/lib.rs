@@ -3,7 +3,13 @@
 
 #![no_std]
 
-use hayabusa_errors::Result;
+mod borsh;
+pub use borsh::*;
+
+use bytemuck::Pod;
+use core::{marker::PhantomData, mem::MaybeUninit};
+use hayabusa_errors::{ProgramError, Result};
+use solana_address::Address;
 
 /// Trait for types that can be decoded from instruction data.
 ///
@@ -105,4 +111,541 @@ pub trait DecodeIx<'ix>: Sized {
     /// - Alignment requirements are met (use `read_unaligned` for safety)
     /// - Borrowed data lifetimes are correctly tied to `'ix`
     fn decode(bytes: &'ix [u8]) -> Result<Self>;
-}
\ No newline at end of file
+}
+/// Trait for fixed-size instruction arguments.
+///
+/// `#[derive(DecodeIx)]` decodes every fixed field through this trait instead of reading the
+/// raw type, so types with invalid bit patterns (`bool`, enums, `Option<T>`) are validated
+/// rather than transmuted. The same encoding is used by the generated client and CPI helpers.
+///
+/// # Encoding
+/// - integers: little-endian
+/// - `bool`: one byte, `0` or `1`
+/// - `Address`, `[T; N]`: elements back to back
+/// - `Option<T>`: a tag byte (`0` = `None`, `1` = `Some`) followed by `T::SIZE` bytes, zeroed
+///   for `None`
+/// - `#[derive(IxField)]` enums: the variant's discriminant as one byte
+/// - `#[derive(IxField)]` structs: fields back to back in declaration order
+///
+/// # Example
+/// ```ignore
+/// #[derive(IxField)]
+/// pub enum Side {
+///     Bid,
+///     Ask,
+/// }
+///
+/// #[derive(Discriminator, DecodeIx)]
+/// #[repr(C)]
+/// pub struct PlaceOrderIx {
+///     pub side: Side,
+///     pub price: Option<u64>,
+/// }
+/// ```
+pub trait IxField: Sized {
+    /// Encoded size in bytes.
+    const SIZE: usize;
+
+    /// Decodes the field from exactly `Self::SIZE` bytes.
+    ///
+    /// # Errors
+    /// Returns `ProgramError::InvalidInstructionData` if the bytes are not a valid encoding.
+    fn decode_field(bytes: &[u8]) -> Result<Self>;
+
+    /// Encodes the field into exactly `Self::SIZE` bytes.
+    fn encode_field(&self, bytes: &mut [u8]);
+}
+
+macro_rules! impl_ix_field_int {
+    ($($t:ty),*) => {
+        $(
+            impl IxField for $t {
+                const SIZE: usize = core::mem::size_of::<$t>();
+
+                #[inline(always)]
+                fn decode_field(bytes: &[u8]) -> Result<Self> {
+                    // SAFETY: callers pass exactly SIZE bytes; [u8; N] has alignment 1
+                    Ok(Self::from_le_bytes(unsafe {
+                        *(bytes.as_ptr() as *const [u8; core::mem::size_of::<$t>()])
+                    }))
+                }
+
+                #[inline(always)]
+                fn encode_field(&self, bytes: &mut [u8]) {
+                    bytes.copy_from_slice(&self.to_le_bytes());
+                }
+            }
+        )*
+    };
+}
+
+impl_ix_field_int!(u8, u16, u32, u64, u128, i8, i16, i32, i64, i128);
+
+impl IxField for bool {
+    const SIZE: usize = 1;
+
+    #[inline(always)]
+    fn decode_field(bytes: &[u8]) -> Result<Self> {
+        match bytes[0] {
+            0 => Ok(false),
+            1 => Ok(true),
+            _ => Err(ProgramError::InvalidInstructionData),
+        }
+    }
+
+    #[inline(always)]
+    fn encode_field(&self, bytes: &mut [u8]) {
+        bytes[0] = *self as u8;
+    }
+}
+
+impl IxField for Address {
+    const SIZE: usize = 32;
+
+    #[inline(always)]
+    fn decode_field(bytes: &[u8]) -> Result<Self> {
+        // SAFETY: callers pass exactly SIZE bytes; [u8; 32] has alignment 1
+        Ok(Address::new_from_array(unsafe { *(bytes.as_ptr() as *const [u8; 32]) }))
+    }
+
+    #[inline(always)]
+    fn encode_field(&self, bytes: &mut [u8]) {
+        bytes.copy_from_slice(self.as_ref());
+    }
+}
+
+impl<T: IxField, const N: usize> IxField for [T; N] {
+    const SIZE: usize = T::SIZE * N;
+
+    #[inline(always)]
+    fn decode_field(bytes: &[u8]) -> Result<Self> {
+        let mut out = [const { MaybeUninit::<T>::uninit() }; N];
+
+        for (slot, chunk) in out.iter_mut().zip(bytes.chunks_exact(T::SIZE)) {
+            slot.write(T::decode_field(chunk)?);
+        }
+
+        // SAFETY: every element was written above; MaybeUninit<T> has the layout of T
+        Ok(unsafe { (out.as_ptr() as *const [T; N]).read() })
+    }
+
+    #[inline(always)]
+    fn encode_field(&self, bytes: &mut [u8]) {
+        for (item, chunk) in self.iter().zip(bytes.chunks_exact_mut(T::SIZE)) {
+            item.encode_field(chunk);
+        }
+    }
+}
+
+impl<T: IxField> IxField for Option<T> {
+    const SIZE: usize = 1 + T::SIZE;
+
+    #[inline(always)]
+    fn decode_field(bytes: &[u8]) -> Result<Self> {
+        match bytes[0] {
+            0 => Ok(None),
+            1 => Ok(Some(T::decode_field(&bytes[1..])?)),
+            _ => Err(ProgramError::InvalidInstructionData),
+        }
+    }
+
+    #[inline(always)]
+    fn encode_field(&self, bytes: &mut [u8]) {
+        match self {
+            Some(value) => {
+                bytes[0] = 1;
+                value.encode_field(&mut bytes[1..]);
+            }
+            None => bytes.fill(0),
+        }
+    }
+}
+
+/// Integer types usable as the length prefix of a variable-length instruction argument.
+///
+/// Selected per field with `#[prefix(u8)]`, `#[prefix(u16)]` or `#[prefix(u32)]`; fields
+/// without the attribute use `u32`, matching Borsh.
+pub trait IxPrefix {
+    /// Encoded size of the prefix in bytes.
+    const SIZE: usize;
+
+    /// Reads the element count from exactly `Self::SIZE` bytes.
+    fn decode_len(bytes: &[u8]) -> usize;
+
+    /// Writes `len` into exactly `Self::SIZE` bytes.
+    ///
+    /// # Errors
+    /// Returns `ProgramError::InvalidArgument` if `len` doesn't fit in the prefix.
+    fn encode_len(len: usize, bytes: &mut [u8]) -> Result<()>;
+}
+
+macro_rules! impl_ix_prefix {
+    ($($t:ty),*) => {
+        $(
+            impl IxPrefix for $t {
+                const SIZE: usize = core::mem::size_of::<$t>();
+
+                #[inline(always)]
+                fn decode_len(bytes: &[u8]) -> usize {
+                    // SAFETY: callers pass exactly SIZE bytes; [u8; N] has alignment 1
+                    <$t>::from_le_bytes(unsafe {
+                        *(bytes.as_ptr() as *const [u8; core::mem::size_of::<$t>()])
+                    }) as usize
+                }
+
+                #[inline(always)]
+                fn encode_len(len: usize, bytes: &mut [u8]) -> Result<()> {
+                    let len = <$t>::try_from(len).map_err(|_| ProgramError::InvalidArgument)?;
+                    bytes.copy_from_slice(&len.to_le_bytes());
+
+                    Ok(())
+                }
+            }
+        )*
+    };
+}
+
+impl_ix_prefix!(u8, u16, u32);
+
+/// Trait for length-prefixed instruction arguments borrowed from the instruction data.
+///
+/// The encoding is an [`IxPrefix`] element count followed by `count * ELEM_SIZE` content
+/// bytes. `#[derive(DecodeIx)]` reads the prefix and bounds-checks the content; implementors
+/// only validate the content itself.
+///
+/// Implemented for `&'ix [u8]` (with a `#[prefix(..)]` attribute; without one the slice
+/// takes the remainder of the data), `&'ix str` and [`PodSlice`].
+pub trait IxVarField<'ix>: Sized {
+    /// Size in bytes of one element.
+    const ELEM_SIZE: usize;
+
+    /// Builds the field from its content bytes, `count * ELEM_SIZE` long.
+    ///
+    /// # Errors
+    /// Returns `ProgramError::InvalidInstructionData` if the content is not valid.
+    fn from_content(bytes: &'ix [u8]) -> Result<Self>;
+
+    /// The content bytes written after the prefix.
+    fn content(&self) -> &[u8];
+
+    /// The element count written in the prefix.
+    #[inline(always)]
+    fn count(&self) -> usize {
+        const { assert!(Self::ELEM_SIZE > 0, "IxVarField::ELEM_SIZE can't be zero") };
+
+        self.content().len() / Self::ELEM_SIZE
+    }
+}
+
+impl<'ix> IxVarField<'ix> for &'ix [u8] {
+    const ELEM_SIZE: usize = 1;
+
+    #[inline(always)]
+    fn from_content(bytes: &'ix [u8]) -> Result<Self> {
+        Ok(bytes)
+    }
+
+    #[inline(always)]
+    fn content(&self) -> &[u8] {
+        self
+    }
+}
+
+impl<'ix> IxVarField<'ix> for &'ix str {
+    const ELEM_SIZE: usize = 1;
+
+    #[inline(always)]
+    fn from_content(bytes: &'ix [u8]) -> Result<Self> {
+        core::str::from_utf8(bytes).map_err(|_| ProgramError::InvalidInstructionData)
+    }
+
+    #[inline(always)]
+    fn content(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
+
+/// Zero-copy view over a `[T]` of `Pod` elements in instruction data.
+///
+/// Instruction data is only 1-byte aligned, so the elements can't be borrowed as `&[T]`;
+/// they are read out with unaligned reads instead.
+///
+/// # Example
+/// ```ignore
+/// fn place_orders(ctx: Ctx<'ix, PlaceOrders<'ix>>, orders: PodSlice<'ix, Order>) -> Result<()> {
+///     for order in orders.iter() {
+///         // ...
+///     }
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct PodSlice<'ix, T: Pod> {
+    bytes: &'ix [u8],
+    _marker: PhantomData<T>,
+}
+
+impl<'ix, T: Pod> PodSlice<'ix, T> {
+    /// Size of one element; zero-sized `T` is rejected at compile time.
+    pub(crate) const ELEM_LEN: usize = {
+        assert!(
+            core::mem::size_of::<T>() > 0,
+            "PodSlice elements can't be zero-sized"
+        );
+        core::mem::size_of::<T>()
+    };
+
+    /// Creates a view over `items`, e.g. to pass them to a client builder or CPI helper.
+    #[inline(always)]
+    pub fn from_slice(items: &'ix [T]) -> Self {
+        Self {
+            bytes: bytemuck::cast_slice(items),
+            _marker: PhantomData,
+        }
+    }
+
+    /// Wraps raw element bytes; `bytes.len()` must be a multiple of `size_of::<T>()`.
+    #[inline(always)]
+    pub(crate) fn from_bytes(bytes: &'ix [u8]) -> Self {
+        Self {
+            bytes,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Number of elements.
+    #[inline(always)]
+    pub fn len(&self) -> usize {
+        self.bytes.len() / Self::ELEM_LEN
+    }
+
+    /// Whether the slice has no elements.
+    #[inline(always)]
+    pub fn is_empty(&self) -> bool {
+        self.bytes.is_empty()
+    }
+
+    /// Reads the element at `index`, or `None` if out of bounds.
+    #[inline(always)]
+    pub fn get(&self, index: usize) -> Option<T> {
+        let start = index.checked_mul(Self::ELEM_LEN)?;
+
+        self.bytes
+            .get(start..start + Self::ELEM_LEN)
+            .map(bytemuck::pod_read_unaligned)
+    }
+
+    /// Iterates over the elements by value.
+    #[inline(always)]
+    pub fn iter(&self) -> impl Iterator<Item = T> + 'ix {
+        self.bytes
+            .chunks_exact(Self::ELEM_LEN)
+            .map(bytemuck::pod_read_unaligned)
+    }
+
+    /// The raw element bytes.
+    #[inline(always)]
+    pub fn as_bytes(&self) -> &'ix [u8] {
+        self.bytes
+    }
+}
+
+impl<'ix, T: Pod> IxVarField<'ix> for PodSlice<'ix, T> {
+    const ELEM_SIZE: usize = PodSlice::<'ix, T>::ELEM_LEN;
+
+    #[inline(always)]
+    fn from_content(bytes: &'ix [u8]) -> Result<Self> {
+        Ok(Self::from_bytes(bytes))
+    }
+
+    #[inline(always)]
+    fn content(&self) -> &[u8] {
+        self.bytes
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hayabusa_decode_instruction_derive::{DecodeIx, IxField};
+
+    #[derive(IxField, Clone, Copy, Debug, PartialEq)]
+    enum Side {
+        Bid,
+        Ask = 5,
+    }
+
+    #[derive(IxField, Clone, Copy, Debug, PartialEq)]
+    struct Price {
+        value: u64,
+        side: Side,
+    }
+
+    #[derive(DecodeIx, Debug, PartialEq)]
+    #[repr(C)]
+    struct OrderIx {
+        post_only: bool,
+        price: Option<Price>,
+        levels: [i16; 2],
+        owner: Address,
+    }
+
+    fn round_trip<T: IxField + PartialEq + core::fmt::Debug>(value: T) {
+        let mut bytes = [0u8; 64];
+        value.encode_field(&mut bytes[..T::SIZE]);
+        assert_eq!(T::decode_field(&bytes[..T::SIZE]), Ok(value));
+    }
+
+    #[test]
+    fn ix_field_round_trip() {
+        round_trip(true);
+        round_trip(-7i64);
+        round_trip(Side::Ask);
+        round_trip(Price {
+            value: 9,
+            side: Side::Bid,
+        });
+        round_trip(Some(Price {
+            value: u64::MAX,
+            side: Side::Ask,
+        }));
+        round_trip(None::<Price>);
+        round_trip([i16::MIN, 3]);
+        round_trip(Address::new_from_array([4; 32]));
+
+        // enums encode their discriminant
+        let mut bytes = [0u8; 1];
+        Side::Ask.encode_field(&mut bytes);
+        assert_eq!(bytes, [5]);
+    }
+
+    #[test]
+    fn ix_field_rejects_invalid_encodings() {
+        let err = Some(ProgramError::InvalidInstructionData);
+
+        assert_eq!(bool::decode_field(&[2]).err(), err);
+        assert_eq!(<Option<u8>>::decode_field(&[2, 0]).err(), err);
+        assert_eq!(Side::decode_field(&[1]).err(), err);
+        assert_eq!(Price::decode_field(&[0, 0, 0, 0, 0, 0, 0, 0, 6]).err(), err);
+    }
+
+    #[test]
+    fn decode_ix_fixed_fields() {
+        let mut bytes = [0u8; OrderIx::LEN + 1];
+        bytes[0] = 1;
+        Some(Price {
+            value: 10,
+            side: Side::Ask,
+        })
+        .encode_field(&mut bytes[1..11]);
+        [1i16, -1].encode_field(&mut bytes[11..15]);
+        bytes[15..OrderIx::LEN].fill(8);
+
+        assert_eq!(
+            OrderIx::decode(&bytes[..OrderIx::LEN]),
+            Ok(OrderIx {
+                post_only: true,
+                price: Some(Price {
+                    value: 10,
+                    side: Side::Ask
+                }),
+                levels: [1, -1],
+                owner: Address::new_from_array([8; 32]),
+            })
+        );
+
+        // fixed-size instructions must match the length exactly
+        assert!(OrderIx::decode(&bytes[..OrderIx::LEN - 1]).is_err());
+        assert!(OrderIx::decode(&bytes).is_err());
+
+        bytes[0] = 2;
+        assert_eq!(
+            OrderIx::decode(&bytes[..OrderIx::LEN]),
+            Err(ProgramError::InvalidInstructionData)
+        );
+    }
+
+    #[derive(DecodeIx, Debug)]
+    #[repr(C)]
+    struct ListIx<'ix> {
+        name: &'ix str,
+        #[prefix(u8)]
+        tag: &'ix [u8],
+        counts: PodSlice<'ix, u64>,
+        fee: u16,
+        rest: &'ix [u8],
+    }
+
+    #[derive(DecodeIx, Debug)]
+    #[repr(C)]
+    struct NameIx<'ix> {
+        #[prefix(u16)]
+        name: &'ix str,
+    }
+
+    const LIST: [u8; 35] = [
+        3, 0, 0, 0, b'a', b'b', b'c', // name
+        2, 9, 8, // tag
+        2, 0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, // counts
+        7, 0, // fee
+        1, 2, 3, // rest
+    ];
+
+    #[test]
+    fn decode_ix_length_prefixed_fields() {
+        let ix = ListIx::decode(&LIST).unwrap();
+        assert_eq!(ix.name, "abc");
+        assert_eq!(ix.tag, &[9, 8]);
+        assert_eq!(ix.counts.len(), 2);
+        assert_eq!(ix.counts.get(1), Some(6));
+        assert_eq!(ix.counts.get(2), None);
+        assert!(ix.counts.iter().eq([5, 6]));
+        assert_eq!(ix.fee, 7);
+        assert_eq!(ix.rest, &[1, 2, 3]);
+
+        // the remainder slice may be empty, but the fixed field after the prefixed ones can't
+        assert_eq!(ListIx::decode(&LIST[..LIST.len() - 3]).unwrap().rest, &[]);
+        assert!(ListIx::decode(&LIST[..LIST.len() - 4]).is_err());
+
+        // the encoded counts and prefixes round trip
+        let orders = [5u64, 6];
+        let counts = PodSlice::from_slice(&orders);
+        assert_eq!(counts.as_bytes(), &LIST[14..30]);
+        assert_eq!(IxVarField::count(&counts), 2);
+
+        let mut prefix = [0u8; 1];
+        assert_eq!(u8::encode_len(2, &mut prefix), Ok(()));
+        assert_eq!(prefix, [2]);
+        assert_eq!(
+            u8::encode_len(256, &mut prefix),
+            Err(ProgramError::InvalidArgument)
+        );
+    }
+
+    #[test]
+    fn decode_ix_rejects_invalid_length_prefixed_fields() {
+        let err = Some(ProgramError::InvalidInstructionData);
+
+        // a prefix past the end of the data
+        let mut bytes = LIST;
+        bytes[0] = 0xff;
+        assert_eq!(ListIx::decode(&bytes).err(), err);
+
+        // an element count far larger than the data
+        let mut bytes = LIST;
+        bytes[10..14].copy_from_slice(&u32::MAX.to_le_bytes());
+        assert_eq!(ListIx::decode(&bytes).err(), err);
+
+        // invalid UTF-8
+        assert_eq!(NameIx::decode(&[2, 0, 0xc3, 0x28]).err(), err);
+        assert_eq!(NameIx::decode(&[2, 0, 0xc3, 0xa9]).unwrap().name, "é");
+
+        // without a remainder slice, trailing bytes are rejected
+        assert_eq!(NameIx::decode(&[1, 0, b'a', 0]).err(), err);
+        assert_eq!(NameIx::decode(&[1]).err(), err);
+    }
+
+    impl OrderIx {
+        const LEN: usize = 1 + 10 + 4 + 32;
+    }
+}
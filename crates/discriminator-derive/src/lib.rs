@@ -4,17 +4,24 @@
 use proc_macro::TokenStream;
 use quote::quote;
 use sha2::{Digest, Sha256};
-use syn::{parse_macro_input, DeriveInput};
+use syn::{parse_macro_input, DeriveInput, Expr, ExprLit, Lit, MetaNameValue};
 
-#[proc_macro_derive(Discriminator)]
+/// Hashes the type name, or the identifier given by `#[discriminator(name = "...")]`, e.g.
+/// `"spl-transfer-hook-interface:execute"` to implement an SPL interface instruction.
+#[proc_macro_derive(Discriminator, attributes(discriminator))]
 pub fn derive_discriminator(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
     let name = &input.ident;
 
     let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
 
+    let name_str = match discriminator_name(&input) {
+        Ok(Some(name)) => name,
+        Ok(None) => name.to_string(),
+        Err(err) => return err.into_compile_error().into(),
+    };
+
     // Generate the discriminator using the hasher
-    let name_str = name.to_string();
     let hasher = DiscriminatorHasher::new(&name_str);
     let discriminator = hasher.hash_and_extract_discriminator();
 
@@ -27,6 +34,22 @@ pub fn derive_discriminator(input: TokenStream) -> TokenStream {
     TokenStream::from(expanded)
 }
 
+fn discriminator_name(input: &DeriveInput) -> syn::Result<Option<String>> {
+    let Some(attr) = input.attrs.iter().find(|a| a.path().is_ident("discriminator")) else {
+        return Ok(None);
+    };
+
+    let option: MetaNameValue = attr.parse_args()?;
+    if !option.path.is_ident("name") {
+        return Err(syn::Error::new_spanned(option.path, "expected `name = \"...\"`"));
+    }
+
+    match option.value {
+        Expr::Lit(ExprLit { lit: Lit::Str(name), .. }) => Ok(Some(name.value())),
+        value => Err(syn::Error::new_spanned(value, "expected a string literal")),
+    }
+}
+
 struct DiscriminatorHasher<'a> {
     pub identifier: &'a str,
 }
@@ -1,15 +1,22 @@
 use proc_macro::TokenStream;
 use quote::{quote, ToTokens};
-use syn::{parse_macro_input, ItemEnum};
+use syn::{parse_macro_input, Data, DeriveInput, Fields, ItemEnum};
 
 /// Usage:
 ///   #[error]
-///   pub enum ArcaneError { A, B, C }
+///   pub enum ArcaneError {
+///       #[msg("vault is locked")]
+///       A,
+///       B,
+///       C,
+///   }
 ///
 /// Expands to:
+///   #[derive(ErrorInfo)]
 ///   #[repr(u32)]
 ///   pub enum ArcaneError { A = 200, B, C }
-///   impl From<ArcaneError> for ProgramError { ... }
+///
+/// Codes below 200 are reserved for hayabusa's own `ErrorCode`.
 #[proc_macro_attribute]
 pub fn error(_attr: TokenStream, item: TokenStream) -> TokenStream {
     let mut input_enum = parse_macro_input!(item as ItemEnum);
@@ -37,17 +44,96 @@ pub fn error(_attr: TokenStream, item: TokenStream) -> TokenStream {
         input_enum.attrs.push(syn::parse_quote!(#[repr(u32)]));
     }
 
-    let enum_ident = &input_enum.ident;
-
-    // NOTE: We assume ProgramError is in scope at the call site.
+    // NOTE: We assume ErrorInfo is in scope at the call site.
     let expanded = quote! {
+        #[derive(ErrorInfo)]
         #input_enum
+    };
+
+    expanded.into()
+}
+
+/// Derives the code mapping of a fieldless `#[repr(u32)]` error enum:
+/// `From<_> for ProgramError` (as `ProgramError::Custom`), `TryFrom<u32>` and `ErrorInfo`
+/// (names, `#[msg("...")]` messages and the variant list).
+///
+/// Variants without `#[msg]` use their name as message.
+#[proc_macro_derive(ErrorInfo, attributes(msg))]
+pub fn derive_error_info(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    let Data::Enum(data) = &input.data else {
+        return syn::Error::new_spanned(&input, "ErrorInfo can only be derived for enums")
+            .to_compile_error()
+            .into();
+    };
+
+    let mut variants = Vec::new();
+    let mut names = Vec::new();
+    let mut messages = Vec::new();
+    for variant in &data.variants {
+        if !matches!(variant.fields, Fields::Unit) {
+            return syn::Error::new_spanned(variant, "ErrorInfo variants cannot have fields")
+                .to_compile_error()
+                .into();
+        }
+
+        let ident = &variant.ident;
+        let name = syn::LitStr::new(&ident.to_string(), ident.span());
+
+        let mut message = name.clone();
+        for attr in variant.attrs.iter().filter(|a| a.path().is_ident("msg")) {
+            match attr.parse_args::<syn::LitStr>() {
+                Ok(lit) => message = lit,
+                Err(e) => return e.to_compile_error().into(),
+            }
+        }
+
+        variants.push(ident);
+        names.push(name);
+        messages.push(message);
+    }
 
+    let enum_ident = &input.ident;
+
+    // NOTE: We assume ProgramError and ErrorInfo are in scope at the call site.
+    let expanded = quote! {
         impl From<#enum_ident> for ProgramError {
             fn from(error: #enum_ident) -> ProgramError {
                 ProgramError::Custom(error as u32)
             }
         }
+
+        impl TryFrom<u32> for #enum_ident {
+            type Error = ProgramError;
+
+            fn try_from(code: u32) -> ::core::result::Result<Self, Self::Error> {
+                <Self as ErrorInfo>::from_code(code).ok_or(ProgramError::InvalidArgument)
+            }
+        }
+
+        impl ErrorInfo for #enum_ident {
+            const VARIANTS: &'static [Self] = &[#(Self::#variants),*];
+
+            fn name(&self) -> &'static str {
+                match self {
+                    #(Self::#variants => #names,)*
+                }
+            }
+
+            fn message(&self) -> &'static str {
+                match self {
+                    #(Self::#variants => #messages,)*
+                }
+            }
+
+            fn from_code(code: u32) -> Option<Self> {
+                match code {
+                    #(c if c == Self::#variants as u32 => Some(Self::#variants),)*
+                    _ => None,
+                }
+            }
+        }
     };
 
     expanded.into()
@@ -1,50 +1,152 @@
 // Copyright (c) 2026, Arcane Labs <dev@arcane.fi>
 // SPDX-License-Identifier: Apache-2.0
 
+use crate::ErrorInfo;
+use core::ops::Range;
+use hayabusa_errors_attribute_macro::ErrorInfo;
 use solana_program_error::ProgramError;
 
-#[derive(Debug, PartialEq, Eq)]
+/// Custom error code ranges reserved per hayabusa crate.
+///
+/// `#[error]` enums start at [`USER`](error_ranges::USER), right after the reserved block.
+pub mod error_ranges {
+    use super::Range;
+
+    /// Dispatch, syscalls and shared utilities.
+    pub const CORE: Range<u32> = 100..120;
+    /// `hayabusa-accounts` and `hayabusa-context`.
+    pub const ACCOUNTS: Range<u32> = 120..140;
+    /// `hayabusa-ser` and `hayabusa-discriminator`.
+    pub const SER: Range<u32> = 140..160;
+    /// `hayabusa-pda`.
+    pub const PDA: Range<u32> = 160..180;
+    /// `hayabusa-cpi` and the CPI crates.
+    pub const CPI: Range<u32> = 180..200;
+    /// First code of `#[error]` enums.
+    pub const USER: u32 = 200;
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ErrorInfo)]
+#[repr(u32)]
 pub enum ErrorCode {
+    // core
+    #[msg("unknown instruction")]
     UnknownInstruction = 100,
+    #[msg("buffer is full")]
     BufferFull,
-    InvalidAccountDiscriminator,
+    #[msg("syscall failed")]
+    SyscallFailed,
+    #[msg("index out of bounds")]
+    InvalidIndex,
+
+    // accounts
+    #[msg("invalid account")]
+    InvalidAccount = 120,
+    #[msg("account is not a signer")]
     AccountNotSigner,
-    InvalidAccount,
+    #[msg("account is not writable")]
     AccountNotWritable,
+    #[msg("invalid program")]
     InvalidProgram,
-    InvalidSeeds,
-    SyscallFailed,
+    #[msg("program account is not executable")]
+    ProgramAccountNotExecutable,
+
+    // ser
+    #[msg("invalid account discriminator")]
+    InvalidAccountDiscriminator = 140,
+
+    // pda
+    #[msg("invalid PDA seeds")]
+    InvalidSeeds = 160,
+    #[msg("seed exceeds the maximum seed length")]
     SeedsTooLong,
+    #[msg("too many seeds")]
     TooManySeeds,
-    InvalidIndex,
-    ProgramAccountNotExecutable,
+
+    // cpi
+    #[msg("invalid return data")]
+    InvalidReturnData = 180,
 }
 
-impl TryFrom<u32> for ErrorCode {
-    type Error = ProgramError;
-
-    fn try_from(value: u32) -> Result<Self, Self::Error> {
-        match value {
-            100 => Ok(ErrorCode::UnknownInstruction),
-            101 => Ok(ErrorCode::BufferFull),
-            102 => Ok(ErrorCode::InvalidAccountDiscriminator),
-            105 => Ok(ErrorCode::AccountNotSigner),
-            106 => Ok(ErrorCode::InvalidAccount),
-            107 => Ok(ErrorCode::AccountNotWritable),
-            108 => Ok(ErrorCode::InvalidProgram),
-            109 => Ok(ErrorCode::InvalidSeeds),
-            110 => Ok(ErrorCode::SyscallFailed),
-            111 => Ok(ErrorCode::SeedsTooLong),
-            112 => Ok(ErrorCode::TooManySeeds),
-            113 => Ok(ErrorCode::InvalidIndex),
-            114 => Ok(ErrorCode::ProgramAccountNotExecutable),
-            _ => Err(ProgramError::InvalidArgument),
+impl ErrorCode {
+    /// The reserved range the code belongs to.
+    pub fn range(self) -> Range<u32> {
+        use error_ranges::*;
+
+        match self {
+            Self::UnknownInstruction | Self::BufferFull | Self::SyscallFailed | Self::InvalidIndex => {
+                CORE
+            }
+            Self::InvalidAccount
+            | Self::AccountNotSigner
+            | Self::AccountNotWritable
+            | Self::InvalidProgram
+            | Self::ProgramAccountNotExecutable => ACCOUNTS,
+            Self::InvalidAccountDiscriminator => SER,
+            Self::InvalidSeeds | Self::SeedsTooLong | Self::TooManySeeds => PDA,
+            Self::InvalidReturnData => CPI,
         }
     }
 }
 
-impl From<ErrorCode> for ProgramError {
-    fn from(e: ErrorCode) -> Self {
-        ProgramError::Custom(e as u32)
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, ErrorInfo)]
+    #[repr(u32)]
+    enum UserError {
+        #[msg("vault is locked")]
+        Locked = error_ranges::USER,
+        Paused,
+        Skipped = 250,
+    }
+
+    fn assert_round_trip<E>()
+    where
+        E: ErrorInfo + Copy + PartialEq + core::fmt::Debug + TryFrom<u32, Error = ProgramError>,
+        ProgramError: From<E>,
+    {
+        for &variant in E::VARIANTS {
+            let ProgramError::Custom(code) = ProgramError::from(variant) else {
+                panic!("{} is not a custom error", variant.name());
+            };
+
+            assert_eq!(E::try_from(code), Ok(variant));
+            assert_eq!(E::from_code(code), Some(variant));
+            assert_eq!(E::from_program_error(&ProgramError::Custom(code)), Some(variant));
+        }
+    }
+
+    #[test]
+    fn error_code_round_trips() {
+        assert_round_trip::<ErrorCode>();
+        assert_eq!(ErrorCode::try_from(99), Err(ProgramError::InvalidArgument));
+        assert_eq!(ErrorCode::try_from(error_ranges::USER), Err(ProgramError::InvalidArgument));
+    }
+
+    #[test]
+    fn error_codes_stay_in_their_range() {
+        for &code in ErrorCode::VARIANTS {
+            assert!(code.range().contains(&(code as u32)), "{} out of range", code.name());
+        }
+    }
+
+    #[test]
+    fn user_errors_round_trip() {
+        assert_round_trip::<UserError>();
+        assert_eq!(UserError::Paused as u32, 201);
+        assert_eq!(UserError::Locked.message(), "vault is locked");
+        assert_eq!(UserError::Paused.message(), "Paused");
+        assert_eq!(UserError::try_from(202), Err(ProgramError::InvalidArgument));
+
+        assert_eq!(
+            crate::describe_error::<UserError>(&ProgramError::Custom(250)),
+            Some(("Skipped", "Skipped"))
+        );
+        assert_eq!(
+            crate::describe_error::<UserError>(&ErrorCode::InvalidSeeds.into()),
+            Some(("InvalidSeeds", "invalid PDA seeds"))
+        );
     }
 }
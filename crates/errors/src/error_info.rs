@@ -0,0 +1,62 @@
+// Copyright (c) 2026, Arcane Labs <dev@arcane.fi>
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::ErrorCode;
+use solana_program_error::ProgramError;
+
+/// Names and messages of a custom error enum, derived by `#[derive(ErrorInfo)]` (which
+/// `#[error]` adds) from the variants' `#[msg("...")]` attributes.
+pub trait ErrorInfo: Sized + 'static {
+    /// Every variant, in declaration order.
+    const VARIANTS: &'static [Self];
+
+    /// The variant name, e.g. `"InvalidSeeds"`.
+    fn name(&self) -> &'static str;
+
+    /// The `#[msg]` text, or the variant name when there is none.
+    fn message(&self) -> &'static str;
+
+    /// The variant with the custom error code `code`, if any.
+    fn from_code(code: u32) -> Option<Self>;
+
+    /// The variant behind a `ProgramError::Custom`, if any.
+    #[inline(always)]
+    fn from_program_error(error: &ProgramError) -> Option<Self> {
+        match error {
+            ProgramError::Custom(code) => Self::from_code(*code),
+            _ => None,
+        }
+    }
+}
+
+/// Looks up the name and message of a `ProgramError::Custom` code in `E`, then in the
+/// built-in `ErrorCode`.
+///
+/// Meant for host-side tests and clients, to turn `custom program error: 0x6d` back into
+/// `InvalidSeeds`.
+pub fn describe_error<E: ErrorInfo>(error: &ProgramError) -> Option<(&'static str, &'static str)> {
+    if let Some(e) = E::from_program_error(error) {
+        return Some((e.name(), e.message()));
+    }
+
+    ErrorCode::from_program_error(error).map(|e| (e.name(), e.message()))
+}
+
+/// Logs an error before it is returned by `error!`, when the `log-errors` feature of
+/// `hayabusa-utility` is enabled.
+pub trait LogError {
+    fn log_error(&self);
+}
+
+impl<T: ErrorInfo> LogError for T {
+    #[inline(always)]
+    fn log_error(&self) {
+        pinocchio_log::log!("Error: {} ({})", self.name(), self.message());
+    }
+}
+
+// builtin errors are already reported by the runtime
+impl LogError for ProgramError {
+    #[inline(always)]
+    fn log_error(&self) {}
+}
@@ -4,7 +4,10 @@
 #![no_std]
 
 mod error_code;
+mod error_info;
+
 pub use error_code::*;
+pub use error_info::*;
 pub use solana_program_error::ProgramError;
 
 pub type Result<T> = core::result::Result<T, ProgramError>;
@@ -2,92 +2,427 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
 use quote::quote;
-use syn::{parse_macro_input, ItemStruct};
+use syn::{
+    parse_macro_input, spanned::Spanned, Data, DeriveInput, Error, Expr, ExprLit, Field, Fields,
+    Ident, ItemStruct, Lit, Type,
+};
 
 #[proc_macro_attribute]
-pub fn event(_attr: TokenStream, input: TokenStream) -> TokenStream {
+pub fn event(attr: TokenStream, input: TokenStream) -> TokenStream {
+    // `#[event(hex)]` keeps the `EVENT: <hex>` log line instead of `sol_log_data`
+    let hex = match parse_macro_input!(attr as Option<Ident>) {
+        None => false,
+        Some(option) if option == "hex" => true,
+        Some(option) => {
+            return syn::Error::new(option.span(), "unknown #[event] option, expected `hex`")
+                .to_compile_error()
+                .into();
+        }
+    };
+
     let s = parse_macro_input!(input as ItemStruct);
-    let name = &s.ident;
 
-    let fields = match &s.fields {
-        syn::Fields::Named(f) => &f.named,
-        _ => panic!("#[event] requires named fields"),
+    match expand_event(s, hex) {
+        Ok(ts) => ts.into(),
+        Err(e) => e.to_compile_error().into(),
+    }
+}
+
+/// An event field: fixed-size through `EventField`, or length-prefixed through
+/// `EventVarField` when it carries `#[max_len(N)]`.
+struct EventFieldSpec {
+    ident: Ident,
+    ty: Type,
+    max_len: Option<Expr>,
+}
+
+impl EventFieldSpec {
+    fn parse(field: &mut Field) -> Result<Self, Error> {
+        let mut max_len = None;
+        let mut attrs = Vec::new();
+
+        for attr in field.attrs.drain(..) {
+            if attr.path().is_ident("max_len") {
+                max_len = Some(attr.parse_args::<Expr>()?);
+            } else {
+                attrs.push(attr);
+            }
+        }
+        field.attrs = attrs;
+
+        if max_len.is_none() && matches!(field.ty, Type::Reference(_)) {
+            return Err(Error::new(
+                field.ty.span(),
+                "variable-length event fields need a #[max_len(N)] bound",
+            ));
+        }
+
+        Ok(Self {
+            ident: field.ident.clone().unwrap(),
+            ty: field.ty.clone(),
+            max_len,
+        })
+    }
+
+    /// Upper bound of the encoded field.
+    fn max_size(&self) -> TokenStream2 {
+        let ty = &self.ty;
+        match &self.max_len {
+            Some(max_len) => quote! { (EVENT_LEN_PREFIX + #max_len) },
+            None => quote! { <#ty as EventField>::SIZE },
+        }
+    }
+
+    /// Writes the field at `__buf[__off..]` and advances `__off`.
+    fn write(&self, overflow: &TokenStream2) -> TokenStream2 {
+        let ident = &self.ident;
+        let ty = &self.ty;
+
+        match &self.max_len {
+            Some(max_len) => quote! {
+                let __bytes = EventVarField::event_bytes(&self.#ident);
+                if __bytes.len() > #max_len {
+                    #overflow
+                }
+                __buf[__off..__off + EVENT_LEN_PREFIX]
+                    .copy_from_slice(&(__bytes.len() as u32).to_le_bytes());
+                __off += EVENT_LEN_PREFIX;
+                __buf[__off..__off + __bytes.len()].copy_from_slice(__bytes);
+                __off += __bytes.len();
+            },
+            None => quote! {
+                self.#ident.write(&mut __buf[__off..__off + <#ty as EventField>::SIZE]);
+                __off += <#ty as EventField>::SIZE;
+            },
+        }
+    }
+
+    /// Reads the field from `__bytes[__off..]` into a local of the same name and advances
+    /// `__off`.
+    fn read(&self) -> TokenStream2 {
+        let ident = &self.ident;
+        let ty = &self.ty;
+
+        match &self.max_len {
+            Some(max_len) => quote! {
+                let __len = <u32 as EventField>::read(
+                    __bytes
+                        .get(__off..__off + EVENT_LEN_PREFIX)
+                        .ok_or(ProgramError::InvalidArgument)?,
+                )? as usize;
+                __off += EVENT_LEN_PREFIX;
+                if __len > #max_len {
+                    return Err(ProgramError::InvalidArgument);
+                }
+                let #ident = EventVarField::from_event_bytes(
+                    __bytes
+                        .get(__off..__off + __len)
+                        .ok_or(ProgramError::InvalidArgument)?,
+                )?;
+                __off += __len;
+            },
+            None => quote! {
+                let #ident = <#ty as EventField>::read(
+                    __bytes
+                        .get(__off..__off + <#ty as EventField>::SIZE)
+                        .ok_or(ProgramError::InvalidArgument)?,
+                )?;
+                __off += <#ty as EventField>::SIZE;
+            },
+        }
+    }
+}
+
+fn expand_event(mut s: ItemStruct, hex: bool) -> Result<TokenStream2, Error> {
+    let name = s.ident.clone();
+
+    let fields = match &mut s.fields {
+        Fields::Named(f) => f
+            .named
+            .iter_mut()
+            .map(EventFieldSpec::parse)
+            .collect::<Result<Vec<_>, _>>()?,
+        _ => return Err(Error::new(s.span(), "#[event] requires named fields")),
     };
 
-    let field_sizes: Vec<_> =
-        fields.iter().map(|f| {
-            let ty = &f.ty;
-            quote! { <#ty as EventField>::SIZE }
-        }).collect();
+    let field_sizes: Vec<_> = fields.iter().map(EventFieldSpec::max_size).collect();
+    let names: Vec<_> = fields.iter().map(|f| &f.ident).collect();
+    let reads: Vec<_> = fields.iter().map(EventFieldSpec::read).collect();
+
+    // buffers are sized for the largest encoding; variable-length fields above their
+    // bound cannot be written
+    let writes: Vec<_> = fields
+        .iter()
+        .map(|f| f.write(&quote! { return Err(ProgramError::InvalidArgument); }))
+        .collect();
+
+    let max_size = quote! {
+        8usize #( + #field_sizes )*
+    };
+
+    let emit_body = if hex {
+        quote! {
+            const __MAX_SIZE: usize = #max_size;
+
+            /* ---- raw event buffer ---- */
+            let mut __buf = [0u8; __MAX_SIZE];
+            let __off = self.write_event(&mut __buf)?;
+
+            /* ---- hex encoding ---- */
+            const __HEX_LEN: usize = __MAX_SIZE * 2;
+            let mut __hex: [u8; __HEX_LEN] = [0u8; __HEX_LEN];
 
-    // offsets
-    let mut offset = quote! { 8usize };
-    let writes = fields.iter().map(|f| {
-        let ident = f.ident.as_ref().unwrap();
-        let ty = &f.ty;
+            {
+                const HEX: &[u8; 16] = b"0123456789abcdef";
+                let mut i = 0;
+                while i < __off {
+                    let b = __buf[i];
+                    __hex[2*i]     = HEX[(b >> 4) as usize];
+                    __hex[2*i + 1] = HEX[(b & 0x0f) as usize];
+                    i += 1;
+                }
+            }
 
-        let start = offset.clone();
-        let end = quote! { #start + <#ty as EventField>::SIZE };
+            const __PREFIX_LEN: usize = 7;
+            const __LOG_LEN: usize = __PREFIX_LEN + __HEX_LEN;
 
-        offset = end.clone();
+            let mut __logger = logger::Logger::<__LOG_LEN>::default();
+            __logger.append("EVENT: ");
+            // SAFETY: hex output is always valid ASCII
+            __logger.append(unsafe {
+                core::str::from_utf8_unchecked(&__hex[..2 * __off])
+            });
+            __logger.log();
 
+            Ok(())
+        }
+    } else {
         quote! {
-            self.#ident.write(&mut __buf[#start .. #end]);
+            const __MAX_SIZE: usize = #max_size;
+
+            let mut __buf = [0u8; __MAX_SIZE];
+            let __off = self.write_event(&mut __buf)?;
+
+            log_event(&__buf[..__off]);
+
+            Ok(())
         }
-    });
+    };
 
-    let total_size = quote! {
-        8usize #( + #field_sizes )*
+    let (impl_generics, ty_generics, where_clause) = s.generics.split_for_impl();
+
+    // events with variable-length fields borrow them from the decoded bytes
+    let (decode_impl, decode_lifetime) = match s.generics.lifetimes().next() {
+        Some(l) => {
+            let lifetime = &l.lifetime;
+            (
+                quote! {
+                    impl #impl_generics EventDecode<#lifetime> for #name #ty_generics #where_clause
+                },
+                quote!(#lifetime),
+            )
+        }
+        None => (quote! { impl<'__e> EventDecode<'__e> for #name }, quote!('__e)),
     };
 
-    let expanded = quote! {
+    Ok(quote! {
         #[derive(Discriminator)]
         #s
 
-        impl EventBuilder for #name {
-            fn emit(&self) {
-                use ::core::{mem::MaybeUninit, ptr::copy_nonoverlapping};
-
-                const __TOTAL_SIZE: usize = #total_size;
+        impl #impl_generics EventBuilder for #name #ty_generics #where_clause {
+            const MAX_SIZE: usize = #max_size;
 
-                /* ---- raw event buffer ---- */
-                let mut __buf = [0u8; __TOTAL_SIZE];
-
-                // discriminator
+            fn write_event(&self, __buf: &mut [u8]) -> Result<usize> {
                 __buf[..8].copy_from_slice(&Self::DISCRIMINATOR);
 
-                // fields
+                let mut __off = 8usize;
                 #(#writes)*
 
-                /* ---- hex encoding ---- */
-                const __HEX_LEN: usize = __TOTAL_SIZE * 2;
-                let mut __hex: [u8; __HEX_LEN] = [0u8; __HEX_LEN];
-
-                {
-                    const HEX: &[u8; 16] = b"0123456789abcdef";
-                    let mut i = 0;
-                    while i < __TOTAL_SIZE {
-                        let b = __buf[i];
-                        __hex[2*i]     = HEX[(b >> 4) as usize];
-                        __hex[2*i + 1] = HEX[(b & 0x0f) as usize];
-                        i += 1;
-                    }
+                Ok(__off)
+            }
+
+            fn emit(&self) -> Result<()> {
+                #emit_body
+            }
+
+            fn emit_cpi(
+                &self,
+                event_authority: &AccountView,
+                bump: u8,
+                program: &AccountView,
+            ) -> Result<()> {
+                const __MAX_SIZE: usize = #max_size;
+
+                // ix data: event ix tag followed by the raw event
+                let mut __data = [0u8; 8 + __MAX_SIZE];
+                __data[..8].copy_from_slice(&EVENT_IX_TAG_LE);
+
+                let __off = self.write_event(&mut __data[8..])?;
+
+                invoke_event_cpi(&__data[..8 + __off], event_authority, bump, program)
+            }
+        }
+
+        #[cfg(feature = "client")]
+        #decode_impl {
+            fn decode(__bytes: &#decode_lifetime [u8]) -> Result<Self> {
+                if __bytes.get(..8) != Some(Self::DISCRIMINATOR) {
+                    return Err(ProgramError::InvalidArgument);
                 }
 
-                const __PREFIX_LEN: usize = 7;
-                const __LOG_LEN: usize = __PREFIX_LEN + __HEX_LEN;
+                let mut __off = 8usize;
+                #(#reads)*
 
-                let mut __logger = logger::Logger::<__LOG_LEN>::default();
-                __logger.append("EVENT: ");
-                // SAFETY: hex output is always valid ASCII
-                __logger.append(unsafe {
-                    core::str::from_utf8_unchecked(&__hex)
-                });
-                __logger.log();
+                if __off != __bytes.len() {
+                    return Err(ProgramError::InvalidArgument);
+                }
+
+                Ok(Self { #(#names),* })
             }
         }
+    })
+}
+
+/// Derives `EventField` for fieldless enums (one discriminant byte) and structs with named
+/// fields (fields back to back, each through its own `EventField` impl).
+#[proc_macro_derive(EventField)]
+pub fn derive_event_field(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    match expand_event_field(input) {
+        Ok(ts) => ts.into(),
+        Err(e) => e.to_compile_error().into(),
+    }
+}
+
+fn expand_event_field(input: DeriveInput) -> Result<TokenStream2, Error> {
+    let ident = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let (size, write, read) = match &input.data {
+        Data::Enum(e) => {
+            if e.variants.len() > 256 {
+                return Err(Error::new(
+                    ident.span(),
+                    "EventField derive supports at most 256 enum variants",
+                ));
+            }
+
+            // without `#[repr(u8)]`, discriminants above 255 would truncate onto other variants
+            let repr_u8 = input.attrs.iter().any(|a| {
+                a.path().is_ident("repr") && a.parse_args::<Ident>().is_ok_and(|r| r == "u8")
+            });
+
+            let mut variants = Vec::new();
+            let mut discriminant = 0u64;
+            for variant in &e.variants {
+                if !matches!(variant.fields, Fields::Unit) {
+                    return Err(Error::new(
+                        variant.span(),
+                        "EventField derive only supports fieldless enum variants",
+                    ));
+                }
+
+                if !repr_u8 {
+                    if let Some((_, expr)) = &variant.discriminant {
+                        discriminant = match expr {
+                            Expr::Lit(ExprLit { lit: Lit::Int(int), .. }) => int.base10_parse()?,
+                            _ => {
+                                return Err(Error::new(
+                                    expr.span(),
+                                    "EventField derive requires #[repr(u8)] for non-literal \
+                                     discriminants",
+                                ))
+                            }
+                        };
+                    }
+
+                    if discriminant > u8::MAX as u64 {
+                        return Err(Error::new(
+                            variant.span(),
+                            "EventField enum discriminants must fit in a u8",
+                        ));
+                    }
+                    discriminant += 1;
+                }
+
+                variants.push(&variant.ident);
+            }
+
+            (
+                quote!(1usize),
+                quote! {
+                    buf[0] = match self {
+                        #(Self::#variants => Self::#variants as u8,)*
+                    };
+                },
+                quote! {
+                    match buf {
+                        #([tag] if *tag == Self::#variants as u8 => Ok(Self::#variants),)*
+                        _ => Err(ProgramError::InvalidArgument),
+                    }
+                },
+            )
+        }
+        Data::Struct(s) => {
+            let Fields::Named(named) = &s.fields else {
+                return Err(Error::new(
+                    s.fields.span(),
+                    "EventField derive only supports structs with named fields",
+                ));
+            };
+
+            let names: Vec<_> = named.named.iter().map(|f| f.ident.clone().unwrap()).collect();
+            let tys: Vec<_> = named.named.iter().map(|f| f.ty.clone()).collect();
+
+            (
+                quote!(0usize #(+ <#tys as EventField>::SIZE)*),
+                quote! {
+                    let mut __off: usize = 0usize;
+                    #(
+                        self.#names.write(&mut buf[__off..__off + <#tys as EventField>::SIZE]);
+                        __off += <#tys as EventField>::SIZE;
+                    )*
+                },
+                quote! {
+                    if buf.len() != Self::SIZE {
+                        return Err(ProgramError::InvalidArgument);
+                    }
+
+                    let mut __off: usize = 0usize;
+                    #(
+                        let #names = <#tys as EventField>::read(
+                            &buf[__off..__off + <#tys as EventField>::SIZE],
+                        )?;
+                        __off += <#tys as EventField>::SIZE;
+                    )*
+
+                    Ok(Self { #(#names),* })
+                },
+            )
+        }
+        Data::Union(_) => {
+            return Err(Error::new(
+                ident.span(),
+                "EventField derive only supports enums and structs",
+            ))
+        }
     };
 
-    expanded.into()
+    Ok(quote! {
+        impl #impl_generics EventField for #ident #ty_generics #where_clause {
+            const SIZE: usize = #size;
+
+            #[inline(always)]
+            fn write(&self, buf: &mut [u8]) {
+                #write
+            }
+
+            #[inline(always)]
+            fn read(buf: &[u8]) -> Result<Self> {
+                #read
+            }
+        }
+    })
 }
@@ -0,0 +1,57 @@
+// Copyright (c) 2026, Arcane Labs <dev@arcane.fi>
+// SPDX-License-Identifier: Apache-2.0
+
+use hayabusa_common::{AccountView, Address};
+use hayabusa_errors::Result;
+use hayabusa_pda::find_program_address_const;
+use solana_instruction_view::{
+    cpi::{invoke_signed, Seed, Signer},
+    InstructionAccount, InstructionView,
+};
+
+/// Instruction tag prefixed to the data of an `emit_cpi!` self-invocation.
+///
+/// Same value Anchor uses (`sha256("anchor:event")[..8]`), so existing indexers pick the
+/// events up unchanged.
+pub const EVENT_IX_TAG: u64 = 0x1d9acb512ea545e4;
+
+/// Little-endian bytes of [`EVENT_IX_TAG`], as they appear in the instruction data.
+pub const EVENT_IX_TAG_LE: [u8; 8] = EVENT_IX_TAG.to_le_bytes();
+
+/// Seed of the event authority PDA that signs `emit_cpi!` self-invocations.
+pub const EVENT_AUTHORITY_SEED: &[u8] = b"__event_authority";
+
+/// Derives the event authority PDA of `program_id` and its bump.
+///
+/// `#[program(event_cpi)]` evaluates it into a `const` from `crate::ID`, so validating the
+/// event authority on-chain is a single address comparison.
+#[inline]
+pub const fn find_event_authority(program_id: &Address) -> (Address, u8) {
+    find_program_address_const(&[EVENT_AUTHORITY_SEED], program_id)
+}
+
+/// Invokes `program` with `data` as instruction data, signed by its event authority.
+///
+/// `data` must start with [`EVENT_IX_TAG_LE`]; the program's dispatcher recognizes the tag
+/// and returns without running a handler, leaving the event bytes in the transaction's
+/// inner instructions.
+#[inline]
+pub fn invoke_event_cpi(
+    data: &[u8],
+    event_authority: &AccountView,
+    bump: u8,
+    program: &AccountView,
+) -> Result<()> {
+    let instruction_accounts = [InstructionAccount::readonly_signer(event_authority.address())];
+
+    let instruction = InstructionView {
+        program_id: program.address(),
+        accounts: &instruction_accounts,
+        data,
+    };
+
+    let bump = [bump];
+    let seeds = [Seed::from(EVENT_AUTHORITY_SEED), Seed::from(&bump)];
+
+    invoke_signed(&instruction, &[event_authority], &[Signer::from(&seeds)])
+}
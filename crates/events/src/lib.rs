@@ -3,23 +3,168 @@
 
 #![no_std]
 
-use hayabusa_common::Address;
+mod event_cpi;
+#[cfg(feature = "client")]
+mod log;
 
-pub trait EventField {
+pub use event_cpi::*;
+#[cfg(feature = "client")]
+pub use log::*;
+
+use hayabusa_common::{AccountView, Address};
+use hayabusa_errors::{ProgramError, Result};
+
+/// Trait for fixed-size event fields.
+///
+/// # Encoding
+/// - integers: little-endian
+/// - `bool`: one byte, `0` or `1`
+/// - `Address`, `[u8; N]`: raw bytes
+/// - `Option<T>`: a tag byte (`0` = `None`, `1` = `Some`) followed by `T::SIZE` bytes, zeroed
+///   for `None`
+/// - `#[derive(EventField)]` enums: the variant's discriminant as one byte, so discriminants
+///   must fit in a `u8`
+/// - `#[derive(EventField)]` structs: fields back to back in declaration order
+pub trait EventField: Sized {
+    /// Encoded size in bytes.
     const SIZE: usize;
 
     fn write(&self, buf: &mut [u8]);
+
+    /// Reads the field back from the `SIZE` bytes `write` produced.
+    fn read(buf: &[u8]) -> Result<Self>;
 }
 
+/// Trait for variable-length event fields (`&[u8]`, `&str`).
+///
+/// Encoded as a `u32` little-endian length followed by the bytes. `#[event]` requires a
+/// `#[max_len(N)]` bound on these fields, which sizes the emit buffer.
+pub trait EventVarField<'a>: Sized {
+    /// The bytes written after the length prefix.
+    fn event_bytes(&self) -> &[u8];
+
+    /// Reads the field back from the bytes following the length prefix.
+    fn from_event_bytes(bytes: &'a [u8]) -> Result<Self>;
+}
+
+/// Size of the length prefix of variable-length event fields.
+pub const EVENT_LEN_PREFIX: usize = 4;
+
 pub trait EventBuilder {
-    fn emit(&self);
+    /// Size of the largest encoding: the discriminator, fixed-size fields, and
+    /// variable-length fields at their `#[max_len]`.
+    const MAX_SIZE: usize;
+
+    /// Writes the raw event (discriminator followed by the fields) to the front of `buf`,
+    /// which must hold at least `MAX_SIZE` bytes, and returns the encoded length.
+    ///
+    /// Fails with `InvalidArgument` if a variable-length field exceeds its `#[max_len]`.
+    fn write_event(&self, buf: &mut [u8]) -> Result<usize>;
+
+    /// Emits the event as a log line.
+    ///
+    /// Fails with `InvalidArgument` if a variable-length field exceeds its `#[max_len]`.
+    fn emit(&self) -> Result<()>;
+
+    /// Emits the event as the data of a self-CPI signed by the program's event authority.
+    ///
+    /// Use through `emit_cpi!`, which requires `#[program(event_cpi)]`.
+    fn emit_cpi(&self, event_authority: &AccountView, bump: u8, program: &AccountView) -> Result<()>;
 }
 
-/// Emit a hex-encoded event log
+/// Logs raw event bytes (discriminator followed by the fields) as a base64
+/// `Program data:` line.
+#[inline(always)]
+pub fn log_event(data: &[u8]) {
+    hayabusa_syscalls::log_data(&[data]);
+}
+
+/// Emit an event log (`Program data:` by default, `EVENT: <hex>` with `#[event(hex)]`)
 #[macro_export]
 macro_rules! emit {
     ($event:expr) => {
-        $event.emit();
+        $event.emit()
+    };
+}
+
+/// Emit an event through a self-CPI, so it survives log truncation
+///
+/// The accounts struct of `$ctx` must have `event_authority: EventAuthority<'ix>` and
+/// `program: Program<'ix, SelfProgram>` fields, both generated by `#[program(event_cpi)]`.
+#[macro_export]
+macro_rules! emit_cpi {
+    ($ctx:expr, $event:expr) => {
+        $event.emit_cpi(
+            &$ctx.event_authority,
+            $ctx.event_authority.bump(),
+            &$ctx.program,
+        )
+    };
+}
+
+/// Declares the enum of all events a program emits, for `scan_events`.
+///
+/// ```ignore
+/// program_events! {
+///     pub enum CounterEvent {
+///         CounterUpdated,
+///         CounterClosed,
+///     }
+/// }
+///
+/// // events with variable-length fields borrow from the log bytes
+/// program_events! {
+///     pub enum MarketEvent<'a> {
+///         OrderPlaced,
+///         MarketListed<'a>,
+///     }
+/// }
+/// ```
+///
+/// Each variant wraps the `#[event]` struct of the same name. `ProgramEvents` is implemented
+/// under the program's `client` feature.
+#[macro_export]
+macro_rules! program_events {
+    (
+        $(#[$attr:meta])*
+        $vis:vis enum $name:ident<$lt:lifetime> { $($event:ident $(<$event_lt:lifetime>)?),* $(,)? }
+    ) => {
+        $(#[$attr])*
+        $vis enum $name<$lt> {
+            $($event($event $(<$event_lt>)?),)*
+        }
+
+        #[cfg(feature = "client")]
+        impl<$lt> ProgramEvents<$lt> for $name<$lt> {
+            $crate::program_events!(@decode_event $lt, $($event),*);
+        }
+    };
+    (
+        $(#[$attr:meta])*
+        $vis:vis enum $name:ident { $($event:ident),* $(,)? }
+    ) => {
+        $(#[$attr])*
+        $vis enum $name {
+            $($event($event),)*
+        }
+
+        #[cfg(feature = "client")]
+        impl<'__e> ProgramEvents<'__e> for $name {
+            $crate::program_events!(@decode_event '__e, $($event),*);
+        }
+    };
+    (@decode_event $lt:lifetime, $($event:ident),*) => {
+        fn decode_event(bytes: &$lt [u8]) -> Option<Result<Self>> {
+            let discriminator = bytes.get(..8)?;
+
+            $(
+                if discriminator == <$event as Discriminator>::DISCRIMINATOR {
+                    return Some(EventDecode::decode(bytes).map(Self::$event));
+                }
+            )*
+
+            None
+        }
     };
 }
 
@@ -33,6 +178,13 @@ macro_rules! impl_event_field_int {
             fn write(&self, buf: &mut [u8]) {
                 buf.copy_from_slice(&self.to_le_bytes());
             }
+
+            #[inline(always)]
+            fn read(buf: &[u8]) -> Result<Self> {
+                buf.try_into()
+                    .map(<$t>::from_le_bytes)
+                    .map_err(|_| ProgramError::InvalidArgument)
+            }
         }
     };
 }
@@ -42,6 +194,53 @@ impl_event_field_int!(u16);
 impl_event_field_int!(u32);
 impl_event_field_int!(u64);
 impl_event_field_int!(u128);
+impl_event_field_int!(i8);
+impl_event_field_int!(i16);
+impl_event_field_int!(i32);
+impl_event_field_int!(i64);
+impl_event_field_int!(i128);
+
+impl EventField for bool {
+    const SIZE: usize = 1;
+
+    #[inline(always)]
+    fn write(&self, buf: &mut [u8]) {
+        buf[0] = *self as u8;
+    }
+
+    #[inline(always)]
+    fn read(buf: &[u8]) -> Result<Self> {
+        match buf {
+            [0] => Ok(false),
+            [1] => Ok(true),
+            _ => Err(ProgramError::InvalidArgument),
+        }
+    }
+}
+
+impl<T: EventField> EventField for Option<T> {
+    const SIZE: usize = 1 + T::SIZE;
+
+    #[inline(always)]
+    fn write(&self, buf: &mut [u8]) {
+        match self {
+            Some(value) => {
+                buf[0] = 1;
+                value.write(&mut buf[1..]);
+            }
+            None => buf.fill(0),
+        }
+    }
+
+    #[inline(always)]
+    fn read(buf: &[u8]) -> Result<Self> {
+        match buf.split_first() {
+            Some((0, _)) => Ok(None),
+            Some((1, value)) => T::read(value).map(Some),
+            _ => Err(ProgramError::InvalidArgument),
+        }
+    }
+}
 
 impl EventField for Address {
     const SIZE: usize = 32;
@@ -50,6 +249,11 @@ impl EventField for Address {
     fn write(&self, buf: &mut [u8]) {
         buf.copy_from_slice(self.as_ref());
     }
+
+    #[inline(always)]
+    fn read(buf: &[u8]) -> Result<Self> {
+        <[u8; 32]>::read(buf).map(Address::new_from_array)
+    }
 }
 
 impl<const N: usize> EventField for [u8; N] {
@@ -59,4 +263,191 @@ impl<const N: usize> EventField for [u8; N] {
     fn write(&self, buf: &mut [u8]) {
         buf.copy_from_slice(self);
     }
-}
\ No newline at end of file
+
+    #[inline(always)]
+    fn read(buf: &[u8]) -> Result<Self> {
+        buf.try_into().map_err(|_| ProgramError::InvalidArgument)
+    }
+}
+
+impl<'a> EventVarField<'a> for &'a [u8] {
+    #[inline(always)]
+    fn event_bytes(&self) -> &[u8] {
+        self
+    }
+
+    #[inline(always)]
+    fn from_event_bytes(bytes: &'a [u8]) -> Result<Self> {
+        Ok(bytes)
+    }
+}
+
+impl<'a> EventVarField<'a> for &'a str {
+    #[inline(always)]
+    fn event_bytes(&self) -> &[u8] {
+        self.as_bytes()
+    }
+
+    #[inline(always)]
+    fn from_event_bytes(bytes: &'a [u8]) -> Result<Self> {
+        core::str::from_utf8(bytes).map_err(|_| ProgramError::InvalidArgument)
+    }
+}
+#[cfg(all(test, feature = "client"))]
+mod tests {
+    use super::*;
+    use hayabusa_discriminator::Discriminator;
+    use hayabusa_discriminator_derive::Discriminator;
+    use hayabusa_events_attribute_macro::{event, EventField};
+
+    #[derive(EventField, Clone, Copy, Debug, PartialEq)]
+    enum Kind {
+        Limit,
+        Market = 4,
+    }
+
+    #[derive(EventField, Clone, Copy, Debug, PartialEq)]
+    struct Fill {
+        kind: Kind,
+        size: i16,
+    }
+
+    #[event]
+    struct OrderPlaced<'a> {
+        price: i64,
+        post_only: bool,
+        expiry: Option<u32>,
+        fill: Fill,
+        last_fill: Option<Fill>,
+        owner: Address,
+        #[max_len(8)]
+        label: &'a str,
+        #[max_len(4)]
+        memo: &'a [u8],
+    }
+
+    const ORDER: OrderPlaced<'static> = OrderPlaced {
+        price: -5,
+        post_only: true,
+        expiry: None,
+        fill: Fill {
+            kind: Kind::Market,
+            size: -2,
+        },
+        last_fill: Some(Fill {
+            kind: Kind::Limit,
+            size: i16::MIN,
+        }),
+        owner: Address::new_from_array([7; 32]),
+        label: "héllo",
+        memo: &[1, 2],
+    };
+
+    /// Offset of `label`'s length prefix in the encoded `ORDER`.
+    const LABEL: usize = 8 + 8 + 1 + 5 + 3 + 4 + 32;
+
+    fn assert_round_trip(event: &OrderPlaced) {
+        let mut buf = [0u8; OrderPlaced::MAX_SIZE];
+        let len = event.write_event(&mut buf).unwrap();
+        let decoded = OrderPlaced::decode(&buf[..len]).unwrap();
+
+        assert_eq!(
+            (decoded.price, decoded.post_only, decoded.expiry),
+            (event.price, event.post_only, event.expiry)
+        );
+        assert_eq!(
+            (decoded.fill, decoded.last_fill),
+            (event.fill, event.last_fill)
+        );
+        assert_eq!(decoded.owner, event.owner);
+        assert_eq!((decoded.label, decoded.memo), (event.label, event.memo));
+    }
+
+    #[test]
+    fn event_round_trip() {
+        assert_eq!(OrderPlaced::MAX_SIZE, LABEL + 4 + 8 + 4 + 4);
+        assert_round_trip(&ORDER);
+
+        assert_round_trip(&OrderPlaced {
+            price: i64::MAX,
+            post_only: false,
+            expiry: Some(u32::MAX),
+            last_fill: None,
+            label: "",
+            memo: &[],
+            ..ORDER
+        });
+
+        // var fields exactly at their bound
+        assert_round_trip(&OrderPlaced {
+            label: "12345678",
+            memo: &[9; 4],
+            ..ORDER
+        });
+    }
+
+    #[test]
+    fn event_encoding() {
+        let mut buf = [0u8; OrderPlaced::MAX_SIZE];
+        let len = ORDER.write_event(&mut buf).unwrap();
+        assert_eq!(len, LABEL + 4 + 6 + 4 + 2);
+
+        assert_eq!(&buf[..8], OrderPlaced::DISCRIMINATOR);
+        assert_eq!(buf[8..16], (-5i64).to_le_bytes());
+        // `None` is a zero tag over zeroed bytes; enums encode their discriminant
+        assert_eq!(buf[16..27], [1, 0, 0, 0, 0, 0, 4, 0xfe, 0xff, 1, 0]);
+        assert_eq!(buf[27..29], [0, 0x80]);
+        assert_eq!(buf[29..LABEL], [7; 32]);
+        assert_eq!(buf[LABEL..LABEL + 4], 6u32.to_le_bytes());
+        assert_eq!(&buf[LABEL + 4..LABEL + 10], "héllo".as_bytes());
+        assert_eq!(buf[LABEL + 10..len], [2, 0, 0, 0, 1, 2]);
+    }
+
+    #[test]
+    fn event_decode_rejects_malformed_bytes() {
+        let mut buf = [0u8; OrderPlaced::MAX_SIZE + 1];
+        let len = ORDER.write_event(&mut buf).unwrap();
+        let err = Some(ProgramError::InvalidArgument);
+
+        let decode_with = |at: usize, byte: u8| {
+            let mut bytes = buf;
+            bytes[at] = byte;
+            OrderPlaced::decode(&bytes[..len]).err()
+        };
+
+        // trailing and missing bytes
+        assert_eq!(OrderPlaced::decode(&buf[..len + 1]).err(), err);
+        assert_eq!(OrderPlaced::decode(&buf[..len - 1]).err(), err);
+        // discriminator, bool, `Option` tag, enum tag
+        assert_eq!(decode_with(0, buf[0] ^ 1), err);
+        assert_eq!(decode_with(16, 2), err);
+        assert_eq!(decode_with(17, 2), err);
+        assert_eq!(decode_with(22, 1), err);
+        // a length above `#[max_len]`, and invalid UTF-8
+        assert_eq!(decode_with(LABEL, 9), err);
+        assert_eq!(decode_with(LABEL + 5, 0x28), err);
+    }
+
+    #[test]
+    fn oversized_var_field() {
+        let long_label = OrderPlaced {
+            label: "123456789",
+            ..ORDER
+        };
+        let long_memo = OrderPlaced {
+            memo: &[0; 5],
+            ..ORDER
+        };
+
+        for event in [&long_label, &long_memo] {
+            let mut buf = [0u8; OrderPlaced::MAX_SIZE];
+            assert_eq!(
+                event.write_event(&mut buf),
+                Err(ProgramError::InvalidArgument)
+            );
+            assert_eq!(event.emit(), Err(ProgramError::InvalidArgument));
+        }
+
+        assert_eq!(ORDER.emit(), Ok(()));
+    }
+}
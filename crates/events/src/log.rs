@@ -0,0 +1,244 @@
+// Copyright (c) 2026, Arcane Labs <dev@arcane.fi>
+// SPDX-License-Identifier: Apache-2.0
+
+//! Host-side decoding of event logs.
+
+extern crate alloc;
+
+use alloc::vec::Vec;
+use core::str::FromStr;
+use hayabusa_common::Address;
+use hayabusa_errors::Result;
+
+/// Host-side counterpart of `EventBuilder`, generated by `#[event]` under the `client`
+/// feature.
+///
+/// Events with variable-length fields borrow them from `bytes`.
+pub trait EventDecode<'a>: Sized {
+    /// Decodes raw event bytes (discriminator followed by the fields), checking the
+    /// discriminator and the exact length.
+    fn decode(bytes: &'a [u8]) -> Result<Self>;
+}
+
+/// The set of events a program emits, generated by `program_events!`.
+pub trait ProgramEvents<'a>: Sized {
+    /// Decodes `bytes` if their discriminator is one of the set's events, `None` otherwise.
+    fn decode_event(bytes: &'a [u8]) -> Option<Result<Self>>;
+}
+
+/// Decodes every event `program_id` logged in a transaction's log messages, in order.
+///
+/// Lines whose discriminator is not part of `E` are skipped; a known discriminator with a
+/// malformed payload is an error. For event sets that borrow from the log bytes, decode the
+/// output of [`event_logs`] instead.
+pub fn scan_events<E: for<'a> ProgramEvents<'a>>(
+    program_id: &Address,
+    logs: &[impl AsRef<str>],
+) -> Result<Vec<E>> {
+    event_logs(program_id, logs)
+        .iter()
+        .filter_map(|bytes| E::decode_event(bytes))
+        .collect()
+}
+
+/// Returns the raw bytes of every event log line `program_id` emitted, in order.
+///
+/// Only lines emitted while `program_id` is the executing program are considered, so
+/// events of other programs (including CPI callees) are skipped.
+pub fn event_logs(program_id: &Address, logs: &[impl AsRef<str>]) -> Vec<Vec<u8>> {
+    let mut invoke_stack = Vec::new();
+    let mut events = Vec::new();
+
+    for line in logs {
+        let line = line.as_ref();
+
+        if let Some(invoked) = invoked_program(line) {
+            invoke_stack.push(invoked);
+        } else if is_program_exit(line) {
+            invoke_stack.pop();
+        } else if invoke_stack.last() == Some(program_id) {
+            events.extend(event_log_bytes(line));
+        }
+    }
+
+    events
+}
+
+/// Parses `Program <id> invoke [<depth>]`.
+fn invoked_program(line: &str) -> Option<Address> {
+    let rest = line.strip_prefix("Program ")?;
+    let (id, depth) = rest.split_once(' ')?;
+    depth.strip_prefix("invoke [")?;
+
+    Address::from_str(id).ok()
+}
+
+/// Matches `Program <id> success` and `Program <id> failed: <err>`.
+fn is_program_exit(line: &str) -> bool {
+    let Some(rest) = line.strip_prefix("Program ") else {
+        return false;
+    };
+
+    match rest.split_once(' ') {
+        Some((_, status)) => status == "success" || status.starts_with("failed"),
+        None => false,
+    }
+}
+
+/// Prefix of the line `sol_log_data` produces (default `#[event]` encoding).
+pub const PROGRAM_DATA_PREFIX: &str = "Program data: ";
+
+/// Prefix of the line `#[event(hex)]` produces.
+pub const HEX_EVENT_PREFIX: &str = "Program log: EVENT: ";
+
+/// Returns the raw event bytes (discriminator followed by the fields) carried by a log line,
+/// in either encoding.
+///
+/// Returns `None` for unrelated lines, malformed payloads, or payloads shorter than a
+/// discriminator.
+pub fn event_log_bytes(line: &str) -> Option<Vec<u8>> {
+    let bytes = if let Some(data) = line.strip_prefix(PROGRAM_DATA_PREFIX) {
+        // one base64 field per logged slice
+        let mut bytes = Vec::new();
+        for field in data.split(' ') {
+            decode_base64(field, &mut bytes)?;
+        }
+        bytes
+    } else if let Some(hex) = line.strip_prefix(HEX_EVENT_PREFIX) {
+        decode_hex(hex)?
+    } else {
+        return None;
+    };
+
+    (bytes.len() >= 8).then_some(bytes)
+}
+
+fn decode_hex(hex: &str) -> Option<Vec<u8>> {
+    let hex = hex.as_bytes();
+    if hex.len() % 2 != 0 {
+        return None;
+    }
+
+    hex.chunks_exact(2)
+        .map(|pair| Some((hex_value(pair[0])? << 4) | hex_value(pair[1])?))
+        .collect()
+}
+
+fn hex_value(c: u8) -> Option<u8> {
+    match c {
+        b'0'..=b'9' => Some(c - b'0'),
+        b'a'..=b'f' => Some(c - b'a' + 10),
+        b'A'..=b'F' => Some(c - b'A' + 10),
+        _ => None,
+    }
+}
+
+fn decode_base64(field: &str, out: &mut Vec<u8>) -> Option<()> {
+    let field = field.as_bytes();
+    if field.len() % 4 != 0 {
+        return None;
+    }
+
+    let padding = field.iter().rev().take_while(|&&c| c == b'=').count();
+    if padding > 2 {
+        return None;
+    }
+
+    for (i, chunk) in field.chunks_exact(4).enumerate() {
+        let is_last = (i + 1) * 4 == field.len();
+        let pad = if is_last { padding } else { 0 };
+
+        let mut acc = 0u32;
+        for &c in &chunk[..4 - pad] {
+            acc = (acc << 6) | base64_value(c)? as u32;
+        }
+        acc <<= 6 * pad as u32;
+
+        let bytes = acc.to_be_bytes();
+        out.extend_from_slice(&bytes[1..4 - pad]);
+    }
+
+    Some(())
+}
+
+fn base64_value(c: u8) -> Option<u8> {
+    match c {
+        b'A'..=b'Z' => Some(c - b'A'),
+        b'a'..=b'z' => Some(c - b'a' + 26),
+        b'0'..=b'9' => Some(c - b'0' + 52),
+        b'+' => Some(62),
+        b'/' => Some(63),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::{format, string::String, vec};
+    use hayabusa_errors::ProgramError;
+
+    #[derive(Debug, PartialEq)]
+    struct Ping(u8);
+
+    impl ProgramEvents<'_> for Ping {
+        fn decode_event(bytes: &[u8]) -> Option<Result<Self>> {
+            (bytes[..8] == [1; 8]).then(|| match bytes[8..] {
+                [n] => Ok(Ping(n)),
+                _ => Err(ProgramError::InvalidArgument),
+            })
+        }
+    }
+
+    #[test]
+    fn scans_only_the_program_logs() {
+        let program = Address::new_from_array([3; 32]);
+        let other = Address::new_from_array([4; 32]);
+
+        let logs: Vec<String> = vec![
+            format!("Program {program} invoke [1]"),
+            "Program log: EVENT: 010101010101010107".into(),
+            format!("Program {other} invoke [2]"),
+            "Program log: EVENT: 010101010101010108".into(),
+            format!("Program {other} success"),
+            "Program data: AgICAgICAgIJ".into(),
+            "Program data: AQEBAQEBAQEJ".into(),
+            format!("Program {program} success"),
+            "Program log: EVENT: 01010101010101010a".into(),
+        ];
+
+        assert_eq!(scan_events::<Ping>(&program, &logs), Ok(vec![Ping(7), Ping(9)]));
+
+        let malformed = [
+            format!("Program {program} invoke [1]"),
+            "Program log: EVENT: 01010101010101010a0b".into(),
+        ];
+        assert_eq!(
+            scan_events::<Ping>(&program, &malformed),
+            Err(ProgramError::InvalidArgument)
+        );
+    }
+
+    #[test]
+    fn decodes_both_encodings() {
+        let expected = [1u8, 2, 3, 4, 5, 6, 7, 8, 0xff, 0x10];
+
+        assert_eq!(
+            event_log_bytes("Program data: AQIDBAUGBwj/EA==").as_deref(),
+            Some(&expected[..])
+        );
+        assert_eq!(
+            event_log_bytes("Program data: AQIDBAUG BwgA").as_deref(),
+            Some(&[1u8, 2, 3, 4, 5, 6, 7, 8, 0][..])
+        );
+        assert_eq!(
+            event_log_bytes("Program log: EVENT: 0102030405060708ff10").as_deref(),
+            Some(&expected[..])
+        );
+
+        assert_eq!(event_log_bytes("Program data: AQID"), None);
+        assert_eq!(event_log_bytes("Program data: AQIDBAUGBwj/EA="), None);
+        assert_eq!(event_log_bytes("Program log: EVENT: 0102030405060708f"), None);
+        assert_eq!(event_log_bytes("Program log: hello"), None);
+    }
+}
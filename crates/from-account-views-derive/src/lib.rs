@@ -2,9 +2,11 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use proc_macro::TokenStream;
-use quote::quote;
+use proc_macro2::Span;
+use quote::{format_ident, quote};
 use syn::{
-    parse_macro_input, spanned::Spanned, Data, DeriveInput, Fields, Type,
+    parse_macro_input, spanned::Spanned, visit_mut::VisitMut, Data, DeriveInput, Fields, Lifetime,
+    Type,
 };
 
 #[proc_macro_derive(FromAccountViews, attributes(meta))]
@@ -61,27 +63,69 @@ pub fn derive_from_account_views(input: TokenStream) -> TokenStream {
 
     let mut bindings = Vec::new();
     let mut field_idents = Vec::new();
+    let mut client_metas = Vec::new();
+    let mut cpi_accounts = Vec::new();
 
-    for field in fields {
+    for (index, field) in fields.iter().enumerate() {
         let ident = field.ident.as_ref().unwrap();
         let ty = &field.ty;
 
         field_idents.push(ident);
 
+        let mut flags_ty = ty.clone();
+        ElideLifetime(info_lt).visit_type_mut(&mut flags_ty);
+        client_metas.push(quote! {
+            account_meta::<#flags_ty>(self.#ident)
+        });
+        cpi_accounts.push(quote! {
+            <#flags_ty as AccountFlags>::instruction_account(self.#ident.address())
+        });
+
         let meta_expr = match parse_meta(&field.attrs, ty, info_lt) {
             Ok(m) => m,
             Err(e) => return e.to_compile_error().into(),
         };
 
-        bindings.push(quote! {
-            let #ident =
-                <#ty as FromAccountView<#info_lt>>::try_from_account_view(
-                    account_views.next()?,
-                    #meta_expr,
-                )?;
-        });
+        if cfg!(feature = "debug-accounts") {
+            let struct_str = struct_name.to_string();
+            let field_str = ident.to_string();
+
+            bindings.push(quote! {
+                let #ident = {
+                    let __view = match account_views.next() {
+                        Ok(view) => view,
+                        Err(e) => {
+                            log_account_failure(#struct_str, #field_str, #index, None);
+                            return Err(e);
+                        }
+                    };
+
+                    match <#ty as FromAccountView<#info_lt>>::try_from_account_view(
+                        __view,
+                        #meta_expr,
+                    ) {
+                        Ok(account) => account,
+                        Err(e) => {
+                            log_account_failure(#struct_str, #field_str, #index, Some(__view.address()));
+                            return Err(e);
+                        }
+                    }
+                };
+            });
+        } else {
+            bindings.push(quote! {
+                let #ident =
+                    <#ty as FromAccountView<#info_lt>>::try_from_account_view(
+                        account_views.next()?,
+                        #meta_expr,
+                    )?;
+            });
+        }
     }
 
+    let client = expand_client_accounts(&input, &field_idents, &client_metas);
+    let cpi = expand_cpi_accounts(&input, info_lt, &field_idents, &cpi_accounts);
+
     let expanded = quote! {
         impl #impl_generics FromAccountViews<#info_lt>
             for #struct_name #ty_generics #where_clause
@@ -97,11 +141,108 @@ pub fn derive_from_account_views(input: TokenStream) -> TokenStream {
                 })
             }
         }
+
+        #client
+        #cpi
     };
 
     expanded.into()
 }
 
+/// Host-side address set mirroring the account struct, used by the generated
+/// `#[program]` client to build the instruction's account list.
+fn expand_client_accounts(
+    input: &DeriveInput,
+    field_idents: &[&syn::Ident],
+    client_metas: &[proc_macro2::TokenStream],
+) -> proc_macro2::TokenStream {
+    let vis = &input.vis;
+    let accounts_ident = format_ident!("{}Accounts", input.ident);
+    let doc = format!(
+        "Account addresses for [`{}`], in the order they are passed to the instruction.",
+        input.ident,
+    );
+
+    quote! {
+        #[cfg(feature = "client")]
+        #[doc = #doc]
+        #[derive(Clone, Copy, Debug)]
+        #vis struct #accounts_ident {
+            #(pub #field_idents: Address,)*
+        }
+
+        #[cfg(feature = "client")]
+        const _: () = {
+            extern crate alloc;
+
+            impl ToAccountMetas for #accounts_ident {
+                fn to_account_metas(&self) -> alloc::vec::Vec<AccountMeta> {
+                    alloc::vec![
+                        #(#client_metas,)*
+                    ]
+                }
+            }
+        };
+    }
+}
+
+/// Account view set mirroring the account struct, used by the generated `#[program]` CPI
+/// helpers so other programs can invoke this one.
+fn expand_cpi_accounts(
+    input: &DeriveInput,
+    info_lt: &Lifetime,
+    field_idents: &[&syn::Ident],
+    cpi_accounts: &[proc_macro2::TokenStream],
+) -> proc_macro2::TokenStream {
+    let vis = &input.vis;
+    let accounts_ident = format_ident!("{}CpiAccounts", input.ident);
+    let len = field_idents.len();
+    let doc = format!(
+        "Account views for invoking an instruction that takes [`{}`] through CPI.",
+        input.ident,
+    );
+
+    quote! {
+        #[cfg(feature = "cpi")]
+        #[doc = #doc]
+        #vis struct #accounts_ident<#info_lt> {
+            #(pub #field_idents: &#info_lt AccountView,)*
+        }
+
+        #[cfg(feature = "cpi")]
+        impl CheckProgramId for #accounts_ident<'_> {
+            const IDS: &'static [Address] = &[crate::ID];
+        }
+
+        #[cfg(feature = "cpi")]
+        impl<#info_lt> #accounts_ident<#info_lt> {
+            /// Account views in the order the instruction consumes them.
+            #[inline(always)]
+            pub fn account_views(&self) -> [&#info_lt AccountView; #len] {
+                [#(self.#field_idents,)*]
+            }
+
+            /// Instruction accounts with the flags the callee validates.
+            #[inline(always)]
+            pub fn instruction_accounts(&self) -> [InstructionAccount<#info_lt>; #len] {
+                [#(#cpi_accounts,)*]
+            }
+        }
+    }
+}
+
+/// Replaces the struct's lifetime with `'_` so field types can be named from an impl
+/// that doesn't carry it.
+struct ElideLifetime<'a>(&'a Lifetime);
+
+impl VisitMut for ElideLifetime<'_> {
+    fn visit_lifetime_mut(&mut self, lifetime: &mut Lifetime) {
+        if lifetime == self.0 {
+            *lifetime = Lifetime::new("'_", Span::call_site());
+        }
+    }
+}
+
 fn parse_meta(
     attrs: &[syn::Attribute],
     ty: &Type,
@@ -39,7 +39,9 @@ macro_rules! dispatch {
                         .map_err(|_| ProgramError::InvalidInstructionData)?;
 
                     let ctx = Ctx::construct($accounts)?;
+                    // the handler's value (if any) becomes the return data
                     return $handler(ctx, $(ix.$field),*)
+                        .map(|value| ReturnValue::set_return_data(&value))
                         .map_err(Into::into);
                 }
             )+
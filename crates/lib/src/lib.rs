@@ -16,16 +16,19 @@ pub mod system_program {
 
 pub mod prelude {
     pub use super::{instruction, system_program};
+    pub use instruction::{InstructionAccount, InstructionView};
+    pub use solana_instruction_view::cpi::{invoke, invoke_signed};
 
     pub use hayabusa_account_attribute_macro::account;
     pub use hayabusa_accounts::*;
     pub use hayabusa_context::*;
     pub use hayabusa_cpi::*;
+    pub use hayabusa_cpi_accounts_derive::CpiAccounts;
     pub use hayabusa_decode_instruction::*;
     pub use hayabusa_discriminator::*;
     pub use hayabusa_discriminator_derive::Discriminator;
-    pub use hayabusa_errors::{ErrorCode, Result};
-    pub use hayabusa_errors_attribute_macro::error;
+    pub use hayabusa_errors::{describe_error, ErrorCode, ErrorInfo, Result};
+    pub use hayabusa_errors_attribute_macro::{error, ErrorInfo};
     pub use hayabusa_from_account_views_derive::FromAccountViews;
     pub use hayabusa_instruction_dispatch_macro::dispatch;
     pub use hayabusa_len_derive::Len;
@@ -35,10 +38,13 @@ pub mod prelude {
     pub use hayabusa_ser_derive::*;
     pub use hayabusa_utility::{hint::unlikely, take_bytes, *};
     pub use hayabusa_events::*;
-    pub use hayabusa_events_attribute_macro::event;
-    pub use hayabusa_decode_instruction_derive::DecodeIx;
+    pub use hayabusa_events_attribute_macro::{event, EventField};
+    pub use hayabusa_decode_instruction_derive::{DecodeIx, IxField};
     pub use hayabusa_program_attribute_macro::program;
 
+    #[cfg(feature = "client")]
+    pub use hayabusa_client::{account_meta, event_authority_address, AccountDecode, AccountMeta, ToAccountMetas};
+
     #[cfg(feature = "std")]
     pub use hayabusa_entrpouint::default_panic_handler;
     pub use hayabusa_entrypoint::{self, no_allocator, program_entrypoint};
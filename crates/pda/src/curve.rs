@@ -0,0 +1,222 @@
+// Copyright (c) 2026, Arcane Labs <dev@arcane.fi>
+// SPDX-License-Identifier: Apache-2.0
+
+//! `const` check of whether 32 bytes decode to an ed25519 point, so PDAs can be found at
+//! compile time. Mirrors `CompressedEdwardsY::decompress` in `curve25519-dalek`.
+
+/// An element of GF(2^255 - 19) as five 51-bit limbs.
+type Fe = [u64; 5];
+
+const MASK: u64 = (1 << 51) - 1;
+
+const ONE: Fe = [1, 0, 0, 0, 0];
+
+/// `(p - 1) / 2` as little-endian bytes.
+const HALF_P_MINUS_ONE: [u8; 32] = exponent(0xf6, 0x3f);
+
+/// `p - 2` as little-endian bytes.
+const P_MINUS_TWO: [u8; 32] = exponent(0xeb, 0x7f);
+
+/// The curve constant `d = -121665 / 121666`.
+const D: Fe = sub(
+    &[0; 5],
+    &mul(
+        &[121665, 0, 0, 0, 0],
+        &pow(&[121666, 0, 0, 0, 0], &P_MINUS_TWO),
+    ),
+);
+
+/// Returns `true` if `bytes` is the compressed y-coordinate of a point on the curve.
+///
+/// The point exists iff `x² = u / v` has a solution, with `u = y² - 1` and `v = dy² + 1`.
+/// `v` is never zero, so that is iff `uv` is zero or a square (Euler's criterion).
+pub(crate) const fn is_on_curve(bytes: &[u8; 32]) -> bool {
+    let y = from_bytes(bytes);
+    let yy = mul(&y, &y);
+    let u = sub(&yy, &ONE);
+    let v = add(&mul(&yy, &D), &ONE);
+
+    let chi = reduce(&pow(&mul(&u, &v), &HALF_P_MINUS_ONE));
+
+    is_equal(&chi, &[0; 5]) || is_equal(&chi, &ONE)
+}
+
+/// Little-endian exponent bytes `low`, thirty `0xff`s, then `high`.
+const fn exponent(low: u8, high: u8) -> [u8; 32] {
+    let mut bytes = [0xff; 32];
+    bytes[0] = low;
+    bytes[31] = high;
+    bytes
+}
+
+const fn load8(bytes: &[u8; 32], at: usize) -> u64 {
+    let mut value = 0u64;
+    let mut i = 0;
+
+    while i < 8 {
+        value |= (bytes[at + i] as u64) << (8 * i);
+        i += 1;
+    }
+
+    value
+}
+
+/// Decodes the low 255 bits; like `dalek`, values `>= p` are accepted and reduced.
+const fn from_bytes(bytes: &[u8; 32]) -> Fe {
+    [
+        load8(bytes, 0) & MASK,
+        (load8(bytes, 6) >> 3) & MASK,
+        (load8(bytes, 12) >> 6) & MASK,
+        (load8(bytes, 19) >> 1) & MASK,
+        (load8(bytes, 24) >> 12) & MASK,
+    ]
+}
+
+/// Propagates carries so every limb fits in 51 bits, plus a small excess in the first.
+const fn carry(mut limbs: [u128; 5]) -> Fe {
+    limbs[1] += limbs[0] >> 51;
+    limbs[2] += limbs[1] >> 51;
+    limbs[3] += limbs[2] >> 51;
+    limbs[4] += limbs[3] >> 51;
+
+    // 2^255 = 19 (mod p)
+    let first = (limbs[0] & MASK as u128) + (limbs[4] >> 51) * 19;
+
+    [
+        first as u64 & MASK,
+        (limbs[1] as u64 & MASK) + (first >> 51) as u64,
+        limbs[2] as u64 & MASK,
+        limbs[3] as u64 & MASK,
+        limbs[4] as u64 & MASK,
+    ]
+}
+
+const fn add(a: &Fe, b: &Fe) -> Fe {
+    carry([
+        (a[0] + b[0]) as u128,
+        (a[1] + b[1]) as u128,
+        (a[2] + b[2]) as u128,
+        (a[3] + b[3]) as u128,
+        (a[4] + b[4]) as u128,
+    ])
+}
+
+/// `a - b`, with `2p` added first so no limb underflows.
+const fn sub(a: &Fe, b: &Fe) -> Fe {
+    carry([
+        (a[0] + 0xf_ffff_ffff_ffda - b[0]) as u128,
+        (a[1] + 0xf_ffff_ffff_fffe - b[1]) as u128,
+        (a[2] + 0xf_ffff_ffff_fffe - b[2]) as u128,
+        (a[3] + 0xf_ffff_ffff_fffe - b[3]) as u128,
+        (a[4] + 0xf_ffff_ffff_fffe - b[4]) as u128,
+    ])
+}
+
+const fn mul(a: &Fe, b: &Fe) -> Fe {
+    let b1 = b[1] * 19;
+    let b2 = b[2] * 19;
+    let b3 = b[3] * 19;
+    let b4 = b[4] * 19;
+
+    carry([
+        m(a[0], b[0]) + m(a[4], b1) + m(a[3], b2) + m(a[2], b3) + m(a[1], b4),
+        m(a[1], b[0]) + m(a[0], b[1]) + m(a[4], b2) + m(a[3], b3) + m(a[2], b4),
+        m(a[2], b[0]) + m(a[1], b[1]) + m(a[0], b[2]) + m(a[4], b3) + m(a[3], b4),
+        m(a[3], b[0]) + m(a[2], b[1]) + m(a[1], b[2]) + m(a[0], b[3]) + m(a[4], b4),
+        m(a[4], b[0]) + m(a[3], b[1]) + m(a[2], b[2]) + m(a[1], b[3]) + m(a[0], b[4]),
+    ])
+}
+
+const fn m(a: u64, b: u64) -> u128 {
+    a as u128 * b as u128
+}
+
+/// `base^exponent`, with `exponent` as little-endian bytes.
+const fn pow(base: &Fe, exponent: &[u8; 32]) -> Fe {
+    let mut result = ONE;
+    let mut bit = 256;
+
+    while bit > 0 {
+        bit -= 1;
+        result = mul(&result, &result);
+
+        if (exponent[bit / 8] >> (bit % 8)) & 1 == 1 {
+            result = mul(&result, base);
+        }
+    }
+
+    result
+}
+
+/// Fully reduces `a` into `[0, p)`, so equal elements have equal limbs.
+const fn reduce(a: &Fe) -> Fe {
+    let mut out = carry([
+        a[0] as u128,
+        a[1] as u128,
+        a[2] as u128,
+        a[3] as u128,
+        a[4] as u128,
+    ]);
+
+    // q = 1 iff out >= p, i.e. out + 19 carries past 2^255
+    let mut q = (out[0] + 19) >> 51;
+    q = (out[1] + q) >> 51;
+    q = (out[2] + q) >> 51;
+    q = (out[3] + q) >> 51;
+    q = (out[4] + q) >> 51;
+
+    out[0] += 19 * q;
+    out[1] += out[0] >> 51;
+    out[0] &= MASK;
+    out[2] += out[1] >> 51;
+    out[1] &= MASK;
+    out[3] += out[2] >> 51;
+    out[2] &= MASK;
+    out[4] += out[3] >> 51;
+    out[3] &= MASK;
+    out[4] &= MASK;
+
+    out
+}
+
+const fn is_equal(a: &Fe, b: &Fe) -> bool {
+    a[0] == b[0] && a[1] == b[1] && a[2] == b[2] && a[3] == b[3] && a[4] == b[4]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use solana_address::{bytes_are_curve_point, Address};
+
+    #[test]
+    fn matches_dalek_decompression() {
+        // sha256 chain: a spread of on- and off-curve inputs
+        let mut bytes = [0u8; 32];
+        let mut on_curve = 0;
+
+        for i in 0..512u32 {
+            bytes = Address::derive_address(&[&bytes, &i.to_le_bytes()], None, &Address::default())
+                .to_bytes();
+
+            let expected = bytes_are_curve_point(bytes);
+            assert_eq!(is_on_curve(&bytes), expected, "{bytes:?}");
+            on_curve += expected as u32;
+        }
+        assert!(on_curve > 128 && on_curve < 384);
+
+        // edge cases: zero, one, the sign bit, and y >= p
+        let mut one = [0; 32];
+        one[0] = 1;
+        let mut p = [0xff; 32];
+        p[0] = 0xed;
+        p[31] = 0x7f;
+
+        for bytes in [[0; 32], one, [0xff; 32], p] {
+            assert_eq!(
+                is_on_curve(&bytes),
+                bytes_are_curve_point(bytes),
+                "{bytes:?}"
+            );
+        }
+    }
+}
@@ -0,0 +1,74 @@
+// Copyright (c) 2026, Arcane Labs <dev@arcane.fi>
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::curve::is_on_curve;
+use hayabusa_errors::Result;
+use solana_address::Address;
+
+/// Derives the program address of `seeds`, returning its address and bump.
+///
+/// Uses the `sol_try_find_program_address` syscall on-chain and derives it directly
+/// off-chain, so clients and tests can call it too.
+#[inline]
+pub fn find_program_address(seeds: &[&[u8]], program_id: &Address) -> Result<(Address, u8)> {
+    #[cfg(target_os = "solana")]
+    {
+        hayabusa_syscalls::try_find_program_address(seeds, program_id)
+    }
+
+    #[cfg(not(target_os = "solana"))]
+    {
+        Address::try_find_program_address(seeds, program_id)
+            .ok_or(hayabusa_errors::ErrorCode::InvalidSeeds.into())
+    }
+}
+
+/// Derives the program address of `seeds` in a `const` context, returning its address
+/// and bump.
+///
+/// Hashes and checks the curve without syscalls, so it is far slower than
+/// [`find_program_address`] at runtime; use it for `const` items, where it costs nothing
+/// on-chain. Fails to compile if no bump yields an address off the curve.
+pub const fn find_program_address_const<const N: usize>(
+    seeds: &[&[u8]; N],
+    program_id: &Address,
+) -> (Address, u8) {
+    let mut bump = u8::MAX;
+
+    loop {
+        let address = Address::derive_address_const(seeds, Some(bump), program_id);
+
+        if !is_on_curve(address.as_array()) {
+            return (address, bump);
+        }
+
+        if bump == 0 {
+            panic!("unable to find a viable program address bump seed");
+        }
+
+        bump -= 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PROGRAM_ID: Address = Address::new_from_array([7; 32]);
+    const VAULT: (Address, u8) = find_program_address_const(&[b"vault"], &PROGRAM_ID);
+
+    #[test]
+    fn const_matches_runtime_derivation() {
+        assert_eq!(Ok(VAULT), find_program_address(&[b"vault"], &PROGRAM_ID));
+
+        for i in 0..64u8 {
+            let program_id = Address::new_from_array([i; 32]);
+            let seeds: [&[u8]; 2] = [b"seed", &[i]];
+
+            assert_eq!(
+                Ok(find_program_address_const(&seeds, &program_id)),
+                find_program_address(&seeds, &program_id),
+            );
+        }
+    }
+}
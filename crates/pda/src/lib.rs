@@ -2,7 +2,11 @@
 // SPDX-License-Identifier: Apache-2.0
 
 #![no_std]
+#![allow(unexpected_cfgs)] // silence warning about target_os = "solana"
 
 mod check_seeds;
+mod curve;
+mod find_program_address;
 
 pub use check_seeds::*;
+pub use find_program_address::*;
@@ -3,41 +3,153 @@
 
 use proc_macro::TokenStream;
 use proc_macro2::{Ident, Span};
-use quote::quote;
+use quote::{format_ident, quote};
 use syn::{
-    parse_macro_input, FnArg, Item, ItemFn, ItemMod, Pat, Result as SynResult, Type, TypePath, PathArguments,
+    parse::{Parse, ParseStream},
+    parse_macro_input,
+    punctuated::Punctuated,
+    spanned::Spanned,
+    visit_mut::VisitMut,
+    Error, Expr, ExprLit, FnArg, GenericArgument, Item, ItemFn, ItemMod, Lifetime, Lit, LitStr,
+    MetaNameValue, Pat, PathArguments, Result as SynResult, ReturnType, Token, Type, TypePath,
 };
 use heck::ToUpperCamelCase;
 
+/// Generates the dispatcher, instruction structs, client builders and CPI helpers of the
+/// handlers in the module.
+///
+/// A handler marked `#[instruction(interface = "namespace:name")]` implements an SPL interface
+/// instruction: its discriminator hashes that string instead of its instruction struct name,
+/// e.g. `"spl-token-metadata-interface:initialize_account"`. `#[execute]` is short for the
+/// transfer hook `Execute` instruction (`spl-transfer-hook-interface:execute`) Token-2022
+/// invokes.
 #[proc_macro_attribute]
-pub fn program(_attr: TokenStream, item: TokenStream) -> TokenStream {
-    expand_program(parse_macro_input!(item as ItemMod))
-        .unwrap()
+pub fn program(attr: TokenStream, item: TokenStream) -> TokenStream {
+    let options = parse_macro_input!(attr as ProgramOptions);
+
+    expand_program(parse_macro_input!(item as ItemMod), &options)
+        .unwrap_or_else(Error::into_compile_error)
         .into()
 }
 
-fn expand_program(module: ItemMod) -> SynResult<proc_macro2::TokenStream> {
+/// Options accepted by `#[program(...)]`.
+///
+/// - `borsh`: instruction args use the Borsh layout (`#[decode(borsh)]`)
+/// - `event_cpi`: generates `EventAuthority` and `SelfProgram` for `emit_cpi!`, and makes the
+///   dispatcher accept (and ignore) the resulting self-invocations
+#[derive(Default)]
+struct ProgramOptions {
+    borsh: bool,
+    event_cpi: bool,
+}
+
+impl Parse for ProgramOptions {
+    fn parse(input: ParseStream) -> SynResult<Self> {
+        let mut options = Self::default();
+
+        for option in Punctuated::<Ident, Token![,]>::parse_terminated(input)? {
+            match option.to_string().as_str() {
+                "borsh" => options.borsh = true,
+                "event_cpi" => options.event_cpi = true,
+                _ => return Err(Error::new(option.span(), "unknown #[program] option")),
+            }
+        }
+
+        Ok(options)
+    }
+}
+
+fn expand_program(module: ItemMod, options: &ProgramOptions) -> SynResult<proc_macro2::TokenStream> {
     let mod_ident = &module.ident;
     let (_, items) = module.content.expect("inline module required");
 
     let mut instruction_structs = Vec::new();
     let mut dispatch_arms = Vec::new();
+    let mut client_builders = Vec::new();
+    let mut cpi_fns = Vec::new();
     let mut preserved_items = Vec::new();
 
-    for item in items {
-        if let Item::Fn(func) = &item {
-            extract_instruction(func, &mut instruction_structs, &mut dispatch_arms);
+    for mut item in items {
+        if let Item::Fn(func) = &mut item {
+            extract_instruction(
+                func,
+                options,
+                &mut instruction_structs,
+                &mut dispatch_arms,
+                &mut client_builders,
+                &mut cpi_fns,
+            )?;
+
+            // `#[execute]` / `#[instruction(..)]` have been turned into the instruction struct's
+            // discriminator
+            func.attrs
+                .retain(|a| !a.path().is_ident("execute") && !a.path().is_ident("instruction"));
+
+            // `#[prefix(..)]` has been moved onto the instruction struct field
+            for input in func.sig.inputs.iter_mut() {
+                if let FnArg::Typed(pat) = input {
+                    pat.attrs.retain(|a| !a.path().is_ident("prefix"));
+                }
+            }
         }
 
         preserved_items.push(item);
     }
 
+    let (event_cpi_items, event_cpi_dispatch) = if options.event_cpi {
+        (expand_event_cpi(), quote! {
+            // `emit_cpi!` self-invocation: the event is the ix data, nothing to execute
+            if ix_data.starts_with(&EVENT_IX_TAG_LE) {
+                let event_authority = views.first().ok_or(ProgramError::NotEnoughAccountKeys)?;
+
+                if unlikely(!event_authority.is_signer()) {
+                    error_msg!(
+                        "dispatcher: event authority must sign event CPIs.",
+                        ProgramError::MissingRequiredSignature,
+                    );
+                }
+
+                EventAuthority::try_from_account_view(event_authority, NoMeta)?;
+
+                return Ok(());
+            }
+        })
+    } else {
+        (quote! {}, quote! {})
+    };
+
     Ok(quote! {
+        #event_cpi_items
+
         mod instruction {
             use super::*;
             #(#instruction_structs)*
         }
 
+        /// Host-side instruction builders, one per handler.
+        ///
+        /// Each builder returns `(program_id, account_metas, data)` with the accounts in the
+        /// order the handler's `FromAccountViews` struct consumes them.
+        #[cfg(feature = "client")]
+        pub mod client {
+            extern crate alloc;
+
+            use super::*;
+            use super::instruction::*;
+            use alloc::vec::Vec;
+
+            #(#client_builders)*
+        }
+
+        /// Typed CPI helpers for invoking this program from other programs, one per handler.
+        #[cfg(feature = "cpi")]
+        pub mod cpi {
+            use super::*;
+            use super::instruction::*;
+
+            #(#cpi_fns)*
+        }
+
         #[cfg(not(feature = "no-entrypoint"))]
         mod #mod_ident {
             use super::instruction::*;
@@ -51,6 +163,8 @@ fn expand_program(module: ItemMod) -> SynResult<proc_macro2::TokenStream> {
                 views: &[AccountView],
                 ix_data: &[u8],
             ) -> Result<()> {
+                #event_cpi_dispatch
+
                 dispatch!(
                     program_id,
                     ix_data,
@@ -64,11 +178,82 @@ fn expand_program(module: ItemMod) -> SynResult<proc_macro2::TokenStream> {
     })
 }
 
+/// Items generated by `#[program(event_cpi)]`.
+fn expand_event_cpi() -> proc_macro2::TokenStream {
+    quote! {
+        /// Address and bump of the program's event authority PDA, derived at compile time.
+        pub const EVENT_AUTHORITY: (Address, u8) = find_event_authority(&crate::ID);
+
+        /// The program's event authority PDA (`[EVENT_AUTHORITY_SEED]`), which signs the
+        /// self-invocations made by `emit_cpi!`.
+        pub struct EventAuthority<'ix> {
+            pub account_view: &'ix AccountView,
+        }
+
+        impl EventAuthority<'_> {
+            #[inline(always)]
+            pub fn bump(&self) -> u8 {
+                EVENT_AUTHORITY.1
+            }
+        }
+
+        unsafe impl<'ix> FromAccountView<'ix> for EventAuthority<'ix> {
+            type Meta<'a>
+                = NoMeta
+            where
+                'ix: 'a;
+
+            #[inline(always)]
+            fn try_from_account_view<'a>(account_view: &'ix AccountView, _: Self::Meta<'a>) -> Result<Self>
+            where
+                'ix: 'a,
+            {
+                if unlikely(account_view.address() != &EVENT_AUTHORITY.0) {
+                    error_msg!(
+                        "EventAuthority::try_from_account_view: not the event authority.",
+                        ErrorCode::InvalidAccount,
+                    );
+                }
+
+                Ok(Self { account_view })
+            }
+        }
+
+        impl ToAccountView for EventAuthority<'_> {
+            #[inline(always)]
+            fn to_account_view(&self) -> &AccountView {
+                self.account_view
+            }
+        }
+
+        impl AccountFlags for EventAuthority<'_> {}
+
+        impl ::core::ops::Deref for EventAuthority<'_> {
+            type Target = AccountView;
+
+            #[inline(always)]
+            fn deref(&self) -> &Self::Target {
+                self.account_view
+            }
+        }
+
+        /// This program, for the `program: Program<'ix, SelfProgram>` account `emit_cpi!` invokes.
+        pub struct SelfProgram;
+
+        impl ProgramId for SelfProgram {
+            const ID: Address = crate::ID;
+        }
+    }
+}
+
 fn extract_instruction(
     func: &ItemFn,
+    options: &ProgramOptions,
     instruction_structs: &mut Vec<proc_macro2::TokenStream>,
     dispatch_arms: &mut Vec<proc_macro2::TokenStream>,
-) {
+    client_builders: &mut Vec<proc_macro2::TokenStream>,
+    cpi_fns: &mut Vec<proc_macro2::TokenStream>,
+) -> SynResult<()> {
     let fn_name = &func.sig.ident;
     let mut fn_name_str = fn_name.to_string().to_upper_camel_case();
     fn_name_str.push_str("Ix");
@@ -80,8 +265,15 @@ fn extract_instruction(
 
     let mut fields = Vec::new();
     let mut args = Vec::new();
+    let mut client_params = Vec::new();
+    let mut client_encode = Vec::new();
+    let mut cpi_encode = Vec::new();
+    let mut needs_heap = false;
     let mut needs_ix_lifetime = false;
 
+    // args are encoded after the discriminator in the layout `DecodeIx` reads back
+    let mut off = quote! { 8usize };
+
     // skip ctx
     for input in func.sig.inputs.iter().skip(1) {
         let FnArg::Typed(pat) = input else { continue };
@@ -89,14 +281,98 @@ fn extract_instruction(
 
         let ident = &pat_ident.ident;
         let mut ty = (*pat.ty).clone();
+        let prefix_attr = pat.attrs.iter().find(|a| a.path().is_ident("prefix"));
 
-        // detect &[u8]
-        if is_u8_slice_ref(&ty) {
+        let mut client_ty = ty.clone();
+        ElideLifetimes.visit_type_mut(&mut client_ty);
+        client_params.push(quote! { #ident: #client_ty });
+
+        let is_borrowed = is_str_ref(&ty) || is_pod_slice(&ty) || is_u8_slice_ref(&ty);
+        let is_var = is_str_ref(&ty) || is_pod_slice(&ty) || (is_u8_slice_ref(&ty) && prefix_attr.is_some());
+
+        let size = if options.borsh {
+            if let Some(attr) = prefix_attr {
+                return Err(Error::new(
+                    attr.span(),
+                    "#[prefix(..)] is not supported with #[program(borsh)]; Borsh always uses a u32 length prefix",
+                ));
+            }
+
+            if is_borrowed {
+                needs_ix_lifetime = true;
+                IxLifetime.visit_type_mut(&mut ty);
+            }
+            needs_heap = true;
+
+            let encode = quote! {
+                let __len = BorshIxField::borsh_len(&#ident);
+                BorshIxField::encode_borsh(&#ident, &mut ix_data[__off..__off + __len]);
+                __off += __len;
+            };
+            client_encode.push(encode.clone());
+            cpi_encode.push(encode);
+
+            quote! { BorshIxField::borsh_len(&#ident) }
+        } else if is_var {
             needs_ix_lifetime = true;
+            needs_heap = true;
+            IxLifetime.visit_type_mut(&mut ty);
+
+            let prefix = match prefix_attr {
+                Some(attr) => attr.parse_args::<Type>()?,
+                None => syn::parse_quote!(u32),
+            };
+
+            let content = quote! { IxVarField::content(&#ident) };
+            let write_content = quote! {
+                ix_data[#off + <#prefix as IxPrefix>::SIZE..][..#content.len()]
+                    .copy_from_slice(#content);
+            };
+            let len = quote! {
+                <#prefix as IxPrefix>::encode_len(
+                    IxVarField::count(&#ident),
+                    &mut ix_data[#off..#off + <#prefix as IxPrefix>::SIZE],
+                )
+            };
+
+            client_encode.push(quote! {
+                #len.expect("instruction argument too long for its length prefix");
+                #write_content
+            });
+            cpi_encode.push(quote! {
+                #len?;
+                #write_content
+            });
+
+            quote! { <#prefix as IxPrefix>::SIZE + #content.len() }
+        } else if is_u8_slice_ref(&ty) {
+            // detect &[u8]
+            needs_ix_lifetime = true;
+            needs_heap = true;
             ty = syn::parse_quote! { &'ix [u8] };
-        }
 
-        fields.push(quote! { pub #ident: #ty });
+            let encode = quote! {
+                ix_data[#off..#off + #ident.len()].copy_from_slice(#ident);
+            };
+            client_encode.push(encode.clone());
+            cpi_encode.push(encode);
+
+            quote! { #ident.len() }
+        } else {
+            let encode = quote! {
+                IxField::encode_field(
+                    &#ident,
+                    &mut ix_data[#off..#off + <#client_ty as IxField>::SIZE],
+                );
+            };
+            client_encode.push(encode.clone());
+            cpi_encode.push(encode);
+
+            quote! { <#client_ty as IxField>::SIZE }
+        };
+        off = quote! { #off + #size };
+
+        fields.push(quote! { #prefix_attr pub #ident: #ty });
         args.push(quote! { #ident });
     }
 
@@ -106,8 +382,21 @@ fn extract_instruction(
         quote! {}
     };
 
+    let decode_mode = if options.borsh {
+        quote! { #[decode(borsh)] }
+    } else {
+        quote! {}
+    };
+
+    let discriminator_name = match interface_instruction(func)? {
+        Some(name) => quote! { #[discriminator(name = #name)] },
+        None => quote! {},
+    };
+
     instruction_structs.push(quote! {
         #[derive(Discriminator, DecodeIx)]
+        #discriminator_name
+        #decode_mode
         #[repr(C)]
         pub struct #struct_ident #generics {
             #(#fields,)*
@@ -117,6 +406,232 @@ fn extract_instruction(
     dispatch_arms.push(quote! {
         #struct_ident => #fn_name(#(#args),*)
     });
+
+    // the Borsh encoding is written with a running offset
+    let borsh_off = if options.borsh && !args.is_empty() {
+        quote! { let mut __off = 8usize; }
+    } else {
+        quote! {}
+    };
+    let write_disc = quote! {
+        ix_data[..8].copy_from_slice(#struct_ident::DISCRIMINATOR);
+        #borsh_off
+    };
+
+    let ctx_accounts = ctx_accounts_type(func)?;
+    let accounts_ty = with_suffix(&ctx_accounts, "Accounts");
+    let doc = format!("Builds the `{fn_name}` instruction.");
+
+    client_builders.push(quote! {
+        #[doc = #doc]
+        pub fn #fn_name(
+            accounts: &#accounts_ty,
+            #(#client_params,)*
+        ) -> (Address, Vec<AccountMeta>, Vec<u8>) {
+            let mut ix_data = alloc::vec![0u8; #off];
+            #write_disc
+            #(#client_encode)*
+
+            (crate::ID, accounts.to_account_metas(), ix_data)
+        }
+    });
+
+    let cpi_accounts_ty = with_suffix(&ctx_accounts, "CpiAccounts");
+    let doc = format!("Invokes the `{fn_name}` instruction through CPI.");
+
+    // fixed-size args fit in a stack buffer; variable-length args need the heap
+    let ix_data = if needs_heap {
+        quote! {
+            extern crate alloc;
+
+            let mut ix_data = alloc::vec![0u8; #off];
+        }
+    } else {
+        quote! { let mut ix_data = [0u8; #off]; }
+    };
+
+    // handlers returning `Result<T>` hand `T` back to the caller through return data
+    let (cpi_ret, invoke_cpi) = match return_value_type(func) {
+        Some(ret) => (
+            quote! { #ret },
+            quote! { cpi_ctx.invoke_with_return(&instruction, &account_views) },
+        ),
+        None => (
+            quote! { () },
+            quote! { cpi_ctx.invoke(&instruction, &account_views) },
+        ),
+    };
+
+    cpi_fns.push(quote! {
+        #[doc = #doc]
+        #[inline(always)]
+        pub fn #fn_name<'ix>(
+            cpi_ctx: CpiCtx<'ix, '_, '_, '_, #cpi_accounts_ty<'ix>>,
+            #(#client_params,)*
+        ) -> Result<#cpi_ret> {
+            let account_views = cpi_ctx.accounts.account_views();
+            let instruction_accounts = cpi_ctx.accounts.instruction_accounts();
+
+            #ix_data
+            #write_disc
+            #(#cpi_encode)*
+
+            let instruction = InstructionView {
+                program_id: &crate::ID,
+                accounts: &instruction_accounts,
+                data: &ix_data,
+            };
+
+            #invoke_cpi
+        }
+    });
+
+    Ok(())
+}
+
+/// The SPL interface instruction a handler implements, hashed for its discriminator:
+/// `#[instruction(interface = "namespace:name")]`, or `#[execute]` for the transfer hook
+/// `Execute` instruction Token-2022 invokes.
+fn interface_instruction(func: &ItemFn) -> SynResult<Option<LitStr>> {
+    let mut interface = None;
+
+    for attr in &func.attrs {
+        let name = if attr.path().is_ident("execute") {
+            LitStr::new("spl-transfer-hook-interface:execute", attr.span())
+        } else if attr.path().is_ident("instruction") {
+            let option: MetaNameValue = attr.parse_args()?;
+            if !option.path.is_ident("interface") {
+                return Err(Error::new(option.path.span(), "expected `interface = \"...\"`"));
+            }
+
+            match option.value {
+                Expr::Lit(ExprLit { lit: Lit::Str(name), .. }) if name.value().contains(':') => name,
+                value => {
+                    return Err(Error::new(
+                        value.span(),
+                        "expected a \"namespace:name\" string literal",
+                    ))
+                }
+            }
+        } else {
+            continue;
+        };
+
+        if interface.is_some() {
+            return Err(Error::new(
+                attr.span(),
+                "a handler implements at most one interface instruction",
+            ));
+        }
+        interface = Some(name);
+    }
+
+    Ok(interface)
+}
+
+/// Extracts `T` from a handler returning `Result<T>`, `None` for `Result<()>`.
+fn return_value_type(func: &ItemFn) -> Option<Type> {
+    let ReturnType::Type(_, ty) = &func.sig.output else { return None };
+    let Type::Path(TypePath { path, .. }) = &**ty else { return None };
+    let segment = path.segments.last().filter(|s| s.ident == "Result")?;
+    let PathArguments::AngleBracketed(generics) = &segment.arguments else { return None };
+
+    match generics.args.first()? {
+        GenericArgument::Type(Type::Tuple(unit)) if unit.elems.is_empty() => None,
+        GenericArgument::Type(ty) => Some(ty.clone()),
+        _ => None,
+    }
+}
+
+/// Names a companion struct generated by `#[derive(FromAccountViews)]`, e.g.
+/// `Foo` -> `FooAccounts` (client) or `FooCpiAccounts` (CPI).
+fn with_suffix(accounts: &TypePath, suffix: &str) -> TypePath {
+    let mut accounts = accounts.clone();
+    let last = accounts.path.segments.last_mut().unwrap();
+    last.ident = format_ident!("{}{}", last.ident, suffix);
+
+    accounts
+}
+
+/// Extracts `Foo` (generics stripped) from the handler's `Ctx<'ix, Foo<'ix>>`.
+fn ctx_accounts_type(func: &ItemFn) -> SynResult<TypePath> {
+    let err = || {
+        Error::new(
+            func.sig.span(),
+            "#[program] handlers must take `Ctx<'ix, Accounts<'ix>>` as their first argument",
+        )
+    };
+
+    let Some(FnArg::Typed(ctx)) = func.sig.inputs.first() else {
+        return Err(err());
+    };
+    let Type::Path(TypePath { path, .. }) = &*ctx.ty else {
+        return Err(err());
+    };
+    let Some(segment) = path.segments.last().filter(|s| s.ident == "Ctx") else {
+        return Err(err());
+    };
+    let PathArguments::AngleBracketed(generics) = &segment.arguments else {
+        return Err(err());
+    };
+    let Some(Type::Path(accounts)) = generics.args.iter().find_map(|arg| match arg {
+        GenericArgument::Type(ty) => Some(ty),
+        _ => None,
+    }) else {
+        return Err(err());
+    };
+
+    let mut accounts = accounts.clone();
+    accounts.path.segments.last_mut().unwrap().arguments = PathArguments::None;
+
+    Ok(accounts)
+}
+
+/// Replaces every lifetime with `'_`; client builders take owned or short-lived arguments.
+struct ElideLifetimes;
+
+impl VisitMut for ElideLifetimes {
+    fn visit_lifetime_mut(&mut self, lifetime: &mut Lifetime) {
+        *lifetime = Lifetime::new("'_", Span::call_site());
+    }
+}
+
+/// Sets every borrowed lifetime in an instruction struct field type to `'ix`, inserting it
+/// where it was elided (`&str`, `PodSlice<T>`).
+struct IxLifetime;
+
+impl VisitMut for IxLifetime {
+    fn visit_type_reference_mut(&mut self, r: &mut syn::TypeReference) {
+        r.lifetime = Some(Lifetime::new("'ix", Span::call_site()));
+        syn::visit_mut::visit_type_reference_mut(self, r);
+    }
+
+    fn visit_path_segment_mut(&mut self, segment: &mut syn::PathSegment) {
+        if segment.ident == "PodSlice" {
+            if let PathArguments::AngleBracketed(generics) = &mut segment.arguments {
+                match generics.args.first_mut() {
+                    Some(GenericArgument::Lifetime(lt)) => {
+                        *lt = Lifetime::new("'ix", Span::call_site());
+                    }
+                    _ => generics.args.insert(0, syn::parse_quote!('ix)),
+                }
+            }
+        }
+        syn::visit_mut::visit_path_segment_mut(self, segment);
+    }
+}
+
+fn is_str_ref(ty: &Type) -> bool {
+    let Type::Reference(r) = ty else { return false };
+    let Type::Path(TypePath { path, .. }) = &*r.elem else { return false };
+
+    path.is_ident("str")
+}
+
+fn is_pod_slice(ty: &Type) -> bool {
+    let Type::Path(TypePath { path, .. }) = ty else { return false };
+
+    path.segments.last().is_some_and(|s| s.ident == "PodSlice")
 }
 
 fn is_u8_slice_ref(ty: &Type) -> bool {
@@ -7,7 +7,10 @@ use hayabusa_common::{AccountView, Address, Ref, RefMut};
 use hayabusa_cpi::CpiCtx;
 use hayabusa_discriminator::Discriminator;
 use hayabusa_errors::{ErrorCode, ProgramError, Result};
-use hayabusa_system_program::instructions::{create_account, CreateAccount};
+use hayabusa_system_program::instructions::{
+    allocate, assign, create_account_with_lamports, minimum_balance, transfer, Allocate, Assign,
+    CreateAccount, Transfer,
+};
 use hayabusa_utility::{error_msg, hint::unlikely, Len, OwnerProgram};
 use solana_instruction_view::cpi::Signer;
 
@@ -17,7 +20,7 @@ pub unsafe trait RawZcDeserialize
 where
     Self: Sized + FromBytesUnchecked + Zc + Deserialize,
 {
-    fn try_deserialize_raw(account_view: &AccountView) -> Result<Ref<Self>>;
+    fn try_deserialize_raw(account_view: &AccountView) -> Result<Ref<'_, Self>>;
 }
 
 // # Safety
@@ -27,7 +30,7 @@ where
     T: Sized + FromBytesUnchecked + Zc + Deserialize + Discriminator + Len + OwnerProgram + Pod,
 {
     #[inline(always)]
-    fn try_deserialize_raw(account_view: &AccountView) -> Result<Ref<T>> {
+    fn try_deserialize_raw(account_view: &AccountView) -> Result<Ref<'_, T>> {
         if unlikely(!account_view.owned_by(&T::OWNER)) {
             error_msg!(
                 "try_deserialize_raw: wrong account owner",
@@ -54,7 +57,7 @@ pub unsafe trait RawZcDeserializeMut
 where
     Self: Sized + FromBytesUnchecked + Zc + Deserialize + DeserializeMut,
 {
-    fn try_deserialize_raw_mut(account_view: &AccountView) -> Result<RefMut<Self>>;
+    fn try_deserialize_raw_mut(account_view: &AccountView) -> Result<RefMut<'_, Self>>;
 }
 
 // # Safety
@@ -71,7 +74,7 @@ where
         + OwnerProgram
         + Pod,
 {
-    fn try_deserialize_raw_mut(account_view: &AccountView) -> Result<RefMut<Self>> {
+    fn try_deserialize_raw_mut(account_view: &AccountView) -> Result<RefMut<'_, Self>> {
         if unlikely(!account_view.owned_by(&T::OWNER)) {
             error_msg!(
                 "try_deserialize_raw_mut: wrong account owner",
@@ -150,6 +153,7 @@ where
     /// that there are no other references to the underlying `AccountView` data,
     ///
     /// and that the `AccountView` data slice len is >8 (to account for discriminator, account data starts at index 8)
+    #[allow(clippy::mut_from_ref)]
     unsafe fn try_deserialize_raw_unchecked_mut(account_view: &AccountView) -> Result<&mut Self>;
 }
 
@@ -206,7 +210,7 @@ pub trait ZcDeserialize
 where
     Self: AnyBitPattern + Discriminator + Len + OwnerProgram + Zc + Deserialize,
 {
-    fn try_deserialize(account_view: &AccountView) -> Result<Ref<Self>> {
+    fn try_deserialize(account_view: &AccountView) -> Result<Ref<'_, Self>> {
         try_deserialize_zc::<Self>(account_view)
     }
 }
@@ -215,7 +219,7 @@ pub trait ZcDeserializeMut
 where
     Self: Pod + Discriminator + Len + OwnerProgram + Zc + Deserialize + DeserializeMut,
 {
-    fn try_deserialize_mut(account_view: &AccountView) -> Result<RefMut<Self>> {
+    fn try_deserialize_mut(account_view: &AccountView) -> Result<RefMut<'_, Self>> {
         try_deserialize_zc_mut::<Self>(account_view)
     }
 }
@@ -234,7 +238,7 @@ where
 }
 
 #[inline(always)]
-pub fn try_deserialize_zc<T>(account_view: &AccountView) -> Result<Ref<T>>
+pub fn try_deserialize_zc<T>(account_view: &AccountView) -> Result<Ref<'_, T>>
 where
     T: AnyBitPattern + Discriminator + Len + OwnerProgram,
 {
@@ -269,7 +273,7 @@ where
 }
 
 #[inline(always)]
-pub fn try_deserialize_zc_mut<T>(account_view: &AccountView) -> Result<RefMut<T>>
+pub fn try_deserialize_zc_mut<T>(account_view: &AccountView) -> Result<RefMut<'_, T>>
 where
     T: Pod + Discriminator + Len + OwnerProgram,
 {
@@ -310,6 +314,8 @@ where
     pub owner_program_id: &'b Address,
     pub payer_account: &'ix AccountView,
     pub system_program: &'ix AccountView,
+    /// Lamports the account is funded with; the rent-exempt minimum when `None`.
+    lamports: Option<u64>,
 }
 
 impl<'ix, 'b> InitAccounts<'ix, 'b>
@@ -326,8 +332,17 @@ where
             owner_program_id,
             payer_account,
             system_program,
+            lamports: None,
         }
     }
+
+    /// Funds the account with `lamports` instead of the rent-exempt minimum, skipping the
+    /// `Rent` sysvar read.
+    #[inline(always)]
+    pub fn with_lamports(mut self, lamports: u64) -> Self {
+        self.lamports = Some(lamports);
+        self
+    }
 }
 
 #[inline(always)]
@@ -339,27 +354,200 @@ pub fn try_initialize_zc<'ix, T>(
 where
     T: Pod + Discriminator + Len + OwnerProgram,
 {
-    // if the account already allocated, this will fail, guarantees that the account is uninitialized
+    init_system_account(target_account, &init_accounts, T::DISCRIMINATED_LEN, signers)?;
+
+    let mut data = target_account.try_borrow_mut()?;
+
+    data[..8].copy_from_slice(T::DISCRIMINATOR);
+
+    Ok(RefMut::map(data, |d| {
+        bytemuck::from_bytes_mut(&mut d[8..T::DISCRIMINATED_LEN])
+    }))
+}
+
+/// Creates `target_account` with `space` bytes owned by `init_accounts.owner_program_id`.
+///
+/// An address anyone can pre-fund would make `create_account` fail, so a system-owned empty
+/// account that already holds lamports is topped up to the required balance, allocated and
+/// assigned instead. Fails if the account is already allocated or owned by another program,
+/// which guarantees that the account is uninitialized.
+#[inline(always)]
+pub fn init_system_account(
+    target_account: &AccountView,
+    init_accounts: &InitAccounts<'_, '_>,
+    space: usize,
+    signers: Option<&[Signer]>,
+) -> Result<()> {
+    let lamports = match init_accounts.lamports {
+        Some(lamports) => lamports,
+        None => minimum_balance(space)?,
+    };
+
+    let current_lamports = target_account.lamports();
+
+    if current_lamports == 0 {
+        let cpi_ctx = CpiCtx::try_new(
+            init_accounts.system_program,
+            CreateAccount {
+                from: init_accounts.payer_account,
+                to: target_account,
+            },
+            signers,
+        )?;
+
+        return create_account_with_lamports(
+            cpi_ctx,
+            lamports,
+            space as u64,
+            init_accounts.owner_program_id,
+        );
+    }
+
+    if unlikely(
+        !target_account.owned_by(&hayabusa_system_program::ID) || target_account.data_len() != 0,
+    ) {
+        error_msg!(
+            "init_system_account: account already in use",
+            ProgramError::AccountAlreadyInitialized,
+        );
+    }
+
+    if current_lamports < lamports {
+        let cpi_ctx = CpiCtx::try_new(
+            init_accounts.system_program,
+            Transfer {
+                from: init_accounts.payer_account,
+                to: target_account,
+            },
+            signers,
+        )?;
+
+        transfer(cpi_ctx, lamports - current_lamports)?;
+    }
+
     let cpi_ctx = CpiCtx::try_new(
         init_accounts.system_program,
-        CreateAccount {
-            from: init_accounts.payer_account,
-            to: target_account,
+        Allocate {
+            account: target_account,
         },
         signers,
     )?;
 
-    create_account(
-        cpi_ctx,
-        init_accounts.owner_program_id,
-        T::DISCRIMINATED_LEN as u64,
+    allocate(cpi_ctx, space as u64)?;
+
+    let cpi_ctx = CpiCtx::try_new(
+        init_accounts.system_program,
+        Assign {
+            account: target_account,
+        },
+        signers,
     )?;
 
-    let mut data = target_account.try_borrow_mut()?;
+    assign(cpi_ctx, init_accounts.owner_program_id)
+}
 
-    data[..8].copy_from_slice(T::DISCRIMINATOR);
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hayabusa_common::account_view::{RuntimeAccount, NOT_BORROWED};
 
-    Ok(RefMut::map(data, |d| {
-        bytemuck::from_bytes_mut(&mut d[8..T::DISCRIMINATED_LEN])
-    }))
+    const OWNER: Address = Address::new_from_array([7; 32]);
+
+    #[repr(C)]
+    struct TestAccount {
+        header: RuntimeAccount,
+        data: [u8; 8],
+    }
+
+    impl TestAccount {
+        fn new(address: u8, owner: Address, lamports: u64, data_len: u64) -> Self {
+            Self {
+                header: RuntimeAccount {
+                    borrow_state: NOT_BORROWED,
+                    is_signer: 1,
+                    is_writable: 1,
+                    address: Address::new_from_array([address; 32]),
+                    owner,
+                    lamports,
+                    data_len,
+                    ..Default::default()
+                },
+                data: [0; 8],
+            }
+        }
+
+        fn view(&mut self) -> AccountView {
+            // SAFETY: the header is followed by at least `data_len` bytes of data
+            unsafe { AccountView::new_unchecked(&mut self.header) }
+        }
+    }
+
+    // Off-chain the CPIs are no-ops, but they still reject borrowed accounts: borrowing the
+    // payer or the target shows which system program instructions get invoked.
+    #[test]
+    fn init_system_account_funds_prefunded_account() {
+        // address `[0; 32]` is the system program id
+        let mut system_program = TestAccount::new(0, Address::default(), 1, 0);
+        let mut payer = TestAccount::new(1, hayabusa_system_program::ID, 10_000, 0);
+        let mut target = TestAccount::new(2, hayabusa_system_program::ID, 500, 0);
+        let (system_program, payer, target) = (system_program.view(), payer.view(), target.view());
+
+        let init_accounts = InitAccounts::new(&OWNER, &payer, &system_program).with_lamports(1_000);
+
+        // the shortfall is transferred from the payer...
+        let borrow = payer.try_borrow().unwrap();
+        assert_eq!(
+            init_system_account(&target, &init_accounts, 8, None),
+            Err(ProgramError::AccountBorrowFailed),
+        );
+        drop(borrow);
+
+        // ...then the target is allocated and assigned
+        let borrow = target.try_borrow().unwrap();
+        assert_eq!(
+            init_system_account(&target, &init_accounts, 8, None),
+            Err(ProgramError::AccountBorrowFailed),
+        );
+        drop(borrow);
+
+        assert_eq!(
+            init_system_account(&target, &init_accounts, 8, None),
+            Ok(())
+        );
+
+        // nothing is transferred once the account holds enough
+        let init_accounts = InitAccounts::new(&OWNER, &payer, &system_program).with_lamports(500);
+        let borrow = payer.try_borrow().unwrap();
+        assert_eq!(
+            init_system_account(&target, &init_accounts, 8, None),
+            Ok(())
+        );
+
+        // an empty account is created and funded by the payer in one instruction
+        let mut empty = TestAccount::new(3, hayabusa_system_program::ID, 0, 0);
+        assert_eq!(
+            init_system_account(&empty.view(), &init_accounts, 8, None),
+            Err(ProgramError::AccountBorrowFailed),
+        );
+        drop(borrow);
+    }
+
+    #[test]
+    fn init_system_account_rejects_accounts_in_use() {
+        let mut system_program = TestAccount::new(0, Address::default(), 1, 0);
+        let mut payer = TestAccount::new(1, hayabusa_system_program::ID, 10_000, 0);
+        let (system_program, payer) = (system_program.view(), payer.view());
+
+        let init_accounts = InitAccounts::new(&OWNER, &payer, &system_program).with_lamports(1_000);
+
+        let mut owned = TestAccount::new(2, OWNER, 500, 0);
+        let mut allocated = TestAccount::new(3, hayabusa_system_program::ID, 500, 8);
+
+        for account in [owned.view(), allocated.view()] {
+            assert_eq!(
+                init_system_account(&account, &init_accounts, 8, None),
+                Err(ProgramError::AccountAlreadyInitialized),
+            );
+        }
+    }
 }
@@ -58,6 +58,82 @@ pub fn try_create_program_address(seeds: &[&[u8]], program_id: &Address) -> Resu
     }
 }
 
+/// Logs `data` through `sol_log_data`, as a `Program data:` line with one base64 field per
+/// slice.
+///
+/// No-op off-chain.
+#[inline(always)]
+pub fn log_data(data: &[&[u8]]) {
+    #[cfg(target_os = "solana")]
+    unsafe {
+        sol_log_data(data.as_ptr() as *const u8, data.len() as u64)
+    };
+
+    #[cfg(not(target_os = "solana"))]
+    core::hint::black_box(data);
+}
+
+/// Maximum size of return data.
+pub const MAX_RETURN_DATA: usize = 1024;
+
+/// Sets the running program's return data through `sol_set_return_data`.
+///
+/// No-op off-chain.
+#[inline(always)]
+pub fn set_return_data(data: &[u8]) {
+    #[cfg(target_os = "solana")]
+    unsafe {
+        sol_set_return_data(data.as_ptr(), data.len() as u64)
+    };
+
+    #[cfg(not(target_os = "solana"))]
+    core::hint::black_box(data);
+}
+
+/// Reads the return data of the last invoked program into `buf` through
+/// `sol_get_return_data`.
+///
+/// Returns the program that set it and its full length, which may exceed `buf.len()` (only
+/// `buf.len()` bytes are copied), or `None` if there is no return data. Always `None`
+/// off-chain.
+#[inline(always)]
+pub fn get_return_data(buf: &mut [u8]) -> Option<(Address, usize)> {
+    #[cfg(target_os = "solana")]
+    {
+        let mut program_id = [0u8; 32];
+
+        let len = unsafe {
+            sol_get_return_data(buf.as_mut_ptr(), buf.len() as u64, program_id.as_mut_ptr())
+        };
+
+        if len == 0 {
+            None
+        } else {
+            Some((Address::new_from_array(program_id), len as usize))
+        }
+    }
+
+    #[cfg(not(target_os = "solana"))]
+    {
+        core::hint::black_box(buf);
+        None
+    }
+}
+
+/// Logs `address` in base58 through `sol_log_pubkey`.
+///
+/// No-op off-chain.
+#[inline(always)]
+pub fn log_address(address: &Address) {
+    #[cfg(target_os = "solana")]
+    unsafe {
+        sol_log_pubkey(address.as_ref().as_ptr())
+    };
+
+    #[cfg(not(target_os = "solana"))]
+    core::hint::black_box(address);
+}
+
 /// Flattens `seeds` into `out`.
 ///
 /// Returns the total number of bytes written.
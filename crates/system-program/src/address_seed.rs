@@ -0,0 +1,44 @@
+// Copyright (c) 2026, Arcane Labs <dev@arcane.fi>
+// SPDX-License-Identifier: Apache-2.0
+
+use core::mem::MaybeUninit;
+use hayabusa_cpi::CpiField;
+use hayabusa_errors::{ErrorCode, Result};
+use hayabusa_utility::write_uninit_bytes;
+use solana_address::MAX_SEED_LEN;
+
+/// Seed of an address derived with `Address::create_with_seed`, as taken by the `*_with_seed`
+/// instructions.
+///
+/// Encoded as a bincode `String`: a `u64` length followed by the UTF-8 bytes.
+#[derive(Clone, Copy)]
+pub struct AddressSeed<'a>(&'a str);
+
+impl<'a> AddressSeed<'a> {
+    /// Returns `ErrorCode::SeedsTooLong` if `seed` exceeds `MAX_SEED_LEN` bytes.
+    #[inline(always)]
+    pub fn try_new(seed: &'a str) -> Result<Self> {
+        if seed.len() > MAX_SEED_LEN {
+            return Err(ErrorCode::SeedsTooLong.into());
+        }
+
+        Ok(Self(seed))
+    }
+
+    #[inline(always)]
+    pub fn as_str(&self) -> &'a str {
+        self.0
+    }
+}
+
+impl CpiField for AddressSeed<'_> {
+    const MAX_SIZE: usize = <u64 as CpiField>::MAX_SIZE + MAX_SEED_LEN;
+
+    #[inline(always)]
+    fn write_cpi_field(&self, bytes: &mut [MaybeUninit<u8>]) -> usize {
+        let prefix = (self.0.len() as u64).write_cpi_field(bytes);
+        write_uninit_bytes(&mut bytes[prefix..], self.0.as_bytes());
+
+        prefix + self.0.len()
+    }
+}
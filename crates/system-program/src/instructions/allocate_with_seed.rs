@@ -0,0 +1,30 @@
+// Copyright (c) 2026, Arcane Labs <dev@arcane.fi>
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::AddressSeed;
+use hayabusa_cpi::{CheckProgramId, CpiCtx, CpiData, CpiField};
+use hayabusa_cpi_accounts_derive::CpiAccounts;
+use hayabusa_errors::Result;
+use solana_account_view::AccountView;
+use solana_address::Address;
+use solana_instruction_view::{InstructionAccount, InstructionView};
+
+#[derive(CpiAccounts)]
+#[cpi(
+    program = crate::ID,
+    discriminator = [9, 0, 0, 0],
+    data(
+        base: &Address = cpi_ctx.base.address(),
+        seed: AddressSeed<'_>,
+        space: u64,
+        owner: &Address,
+    ),
+)]
+pub struct AllocateWithSeed<'ix> {
+    /// Account to be allocated, derived from `base` and the seed
+    #[writable]
+    pub account: &'ix AccountView,
+    /// Base account
+    #[signer]
+    pub base: &'ix AccountView,
+}
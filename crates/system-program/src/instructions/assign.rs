@@ -1,47 +1,18 @@
 // Copyright (c) 2026, Arcane Labs <dev@arcane.fi>
 // SPDX-License-Identifier: Apache-2.0
 
-use hayabusa_cpi::{CheckProgramId, CpiCtx};
+use hayabusa_cpi::{CheckProgramId, CpiCtx, CpiData, CpiField};
+use hayabusa_cpi_accounts_derive::CpiAccounts;
 use hayabusa_errors::Result;
 use solana_account_view::AccountView;
 use solana_address::Address;
-use solana_instruction_view::{
-    cpi::{invoke, invoke_signed},
-    InstructionAccount, InstructionView,
-};
+use solana_instruction_view::{InstructionAccount, InstructionView};
 
+#[derive(CpiAccounts)]
+#[cpi(program = crate::ID, discriminator = [1, 0, 0, 0], data(owner: &Address))]
 pub struct Assign<'ix> {
     /// Account to be assigned to a program
+    #[writable]
+    #[signer]
     pub account: &'ix AccountView,
 }
-
-impl CheckProgramId for Assign<'_> {
-    const ID: Address = crate::ID;
-}
-
-#[inline(always)]
-pub fn assign<'ix>(cpi_ctx: CpiCtx<'ix, '_, '_, '_, Assign<'ix>>, owner: &Address) -> Result<()> {
-    let account_views = [cpi_ctx.account];
-    let instruction_accounts = [InstructionAccount::writable_signer(
-        cpi_ctx.account.address(),
-    )];
-
-    // ix data
-    // - [0..4]: discriminator
-    // - [4..36]: owner pubkey
-    let mut ix_data = [0; 36];
-    ix_data[0] = 1;
-    ix_data[4..36].copy_from_slice(owner.as_ref());
-
-    let instruction = InstructionView {
-        program_id: &crate::ID,
-        accounts: &instruction_accounts,
-        data: &ix_data,
-    };
-
-    if let Some(signers) = cpi_ctx.signers {
-        invoke_signed(&instruction, &account_views, signers)
-    } else {
-        invoke(&instruction, &account_views)
-    }
-}
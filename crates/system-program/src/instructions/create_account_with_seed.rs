@@ -0,0 +1,50 @@
+// Copyright (c) 2026, Arcane Labs <dev@arcane.fi>
+// SPDX-License-Identifier: Apache-2.0
+
+use super::minimum_balance;
+use crate::AddressSeed;
+use hayabusa_cpi::{CheckProgramId, CpiCtx, CpiData, CpiField};
+use hayabusa_cpi_accounts_derive::CpiAccounts;
+use hayabusa_errors::Result;
+use solana_account_view::AccountView;
+use solana_address::Address;
+use solana_instruction_view::{InstructionAccount, InstructionView};
+
+#[derive(CpiAccounts)]
+#[cpi(
+    program = crate::ID,
+    discriminator = [3, 0, 0, 0],
+    name = create_account_with_seed_and_lamports,
+    data(
+        base: &Address = cpi_ctx.base.address(),
+        seed: AddressSeed<'_>,
+        lamports: u64,
+        space: u64,
+        owner_program: &Address,
+    ),
+)]
+pub struct CreateAccountWithSeed<'ix> {
+    /// Funding account
+    #[writable]
+    #[signer]
+    pub from: &'ix AccountView,
+    /// New account, derived from `base` and the seed
+    #[writable]
+    pub to: &'ix AccountView,
+    /// Base account (may be the funding account)
+    #[signer]
+    pub base: &'ix AccountView,
+}
+
+/// Creates `to` funded with the rent-exempt minimum for `space` bytes.
+#[inline]
+pub fn create_account_with_seed<'ix>(
+    cpi_ctx: CpiCtx<'ix, '_, '_, '_, CreateAccountWithSeed<'ix>>,
+    seed: AddressSeed<'_>,
+    owner_program: &Address,
+    space: u64,
+) -> Result<()> {
+    let lamports = minimum_balance(space as usize)?;
+
+    create_account_with_seed_and_lamports(cpi_ctx, seed, lamports, space, owner_program)
+}
@@ -0,0 +1,21 @@
+// Copyright (c) 2026, Arcane Labs <dev@arcane.fi>
+// SPDX-License-Identifier: Apache-2.0
+
+use hayabusa_cpi::{CheckProgramId, CpiCtx, CpiData, CpiField};
+use hayabusa_cpi_accounts_derive::CpiAccounts;
+use hayabusa_errors::Result;
+use solana_account_view::AccountView;
+use solana_address::Address;
+use solana_instruction_view::{InstructionAccount, InstructionView};
+
+#[derive(CpiAccounts)]
+#[cpi(program = crate::ID, discriminator = [6, 0, 0, 0], data(authority: &Address))]
+pub struct InitializeNonceAccount<'ix> {
+    /// Nonce account
+    #[writable]
+    pub nonce_account: &'ix AccountView,
+    /// RecentBlockhashes sysvar
+    pub recent_blockhashes_sysvar: &'ix AccountView,
+    /// Rent sysvar
+    pub rent_sysvar: &'ix AccountView,
+}
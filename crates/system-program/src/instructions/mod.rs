@@ -1,21 +1,164 @@
 // Copyright (c) 2026, Arcane Labs <dev@arcane.fi>
 // SPDX-License-Identifier: Apache-2.0
 
+pub mod advance_nonce_account;
 pub mod allocate;
+pub mod allocate_with_seed;
 pub mod assign;
+pub mod assign_with_seed;
+pub mod authorize_nonce_account;
 pub mod create_account;
+pub mod create_account_with_seed;
+pub mod initialize_nonce_account;
 pub mod transfer;
+pub mod transfer_with_seed;
+pub mod upgrade_nonce_account;
+pub mod withdraw_nonce_account;
 
+pub use advance_nonce_account::*;
 pub use allocate::*;
+pub use allocate_with_seed::*;
 pub use assign::*;
+pub use assign_with_seed::*;
+pub use authorize_nonce_account::*;
 pub use create_account::*;
+pub use create_account_with_seed::*;
+pub use initialize_nonce_account::*;
 pub use transfer::*;
+pub use transfer_with_seed::*;
+pub use upgrade_nonce_account::*;
+pub use withdraw_nonce_account::*;
 
 use hayabusa_errors::Result;
 use hayabusa_sysvars::{rent::Rent, Sysvar};
 
-fn minimum_balance(space: usize) -> Result<u64> {
+/// Rent-exempt minimum balance for `space` bytes, read from the `Rent` sysvar.
+///
+/// Pass the balance to the `*_and_lamports` / `*_with_lamports` variants to skip the sysvar
+/// read.
+#[inline]
+pub fn minimum_balance(space: usize) -> Result<u64> {
     let rent = Rent::get()?;
 
-    Ok(rent.try_minimum_balance(space)?)
+    rent.try_minimum_balance(space)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::AddressSeed;
+    use hayabusa_errors::ErrorCode;
+    use solana_address::Address;
+
+    const BASE: Address = Address::new_from_array([1; 32]);
+    const OWNER: Address = Address::new_from_array([2; 32]);
+
+    /// Concatenates the expected encoding pieces.
+    fn encode<const N: usize>(pieces: &[&[u8]]) -> [u8; N] {
+        let mut out = [0u8; N];
+        let mut off = 0;
+        for piece in pieces {
+            out[off..off + piece.len()].copy_from_slice(piece);
+            off += piece.len();
+        }
+        assert_eq!(off, N);
+
+        out
+    }
+
+    #[test]
+    fn encodes_account_instructions() {
+        assert_eq!(
+            CreateAccount::instruction_data(5, 80, &OWNER).as_slice(),
+            encode::<52>(&[
+                &[0, 0, 0, 0],
+                &5u64.to_le_bytes(),
+                &80u64.to_le_bytes(),
+                OWNER.as_ref()
+            ]),
+        );
+        assert_eq!(
+            Assign::instruction_data(&OWNER).as_slice(),
+            encode::<36>(&[&[1, 0, 0, 0], OWNER.as_ref()]),
+        );
+        assert_eq!(
+            Transfer::instruction_data(7).as_slice(),
+            encode::<12>(&[&[2, 0, 0, 0], &7u64.to_le_bytes()]),
+        );
+        assert_eq!(
+            Allocate::instruction_data(80).as_slice(),
+            encode::<12>(&[&[8, 0, 0, 0], &80u64.to_le_bytes()]),
+        );
+    }
+
+    #[test]
+    fn encodes_seed_instructions() {
+        let seed = AddressSeed::try_new("vault").unwrap();
+        let seed_bytes = encode::<13>(&[&5u64.to_le_bytes(), b"vault"]);
+
+        assert_eq!(
+            CreateAccountWithSeed::instruction_data(&BASE, seed, 5, 80, &OWNER).as_slice(),
+            encode::<97>(&[
+                &[3, 0, 0, 0],
+                BASE.as_ref(),
+                &seed_bytes,
+                &5u64.to_le_bytes(),
+                &80u64.to_le_bytes(),
+                OWNER.as_ref(),
+            ]),
+        );
+        assert_eq!(
+            AllocateWithSeed::instruction_data(&BASE, seed, 80, &OWNER).as_slice(),
+            encode::<89>(&[
+                &[9, 0, 0, 0],
+                BASE.as_ref(),
+                &seed_bytes,
+                &80u64.to_le_bytes(),
+                OWNER.as_ref()
+            ]),
+        );
+        assert_eq!(
+            AssignWithSeed::instruction_data(&BASE, seed, &OWNER).as_slice(),
+            encode::<81>(&[&[10, 0, 0, 0], BASE.as_ref(), &seed_bytes, OWNER.as_ref()]),
+        );
+        assert_eq!(
+            TransferWithSeed::instruction_data(7, seed, &OWNER).as_slice(),
+            encode::<57>(&[
+                &[11, 0, 0, 0],
+                &7u64.to_le_bytes(),
+                &seed_bytes,
+                OWNER.as_ref()
+            ]),
+        );
+
+        assert!(AddressSeed::try_new(core::str::from_utf8(&[b'a'; 32]).unwrap()).is_ok());
+        assert_eq!(
+            AddressSeed::try_new(core::str::from_utf8(&[b'a'; 33]).unwrap()).err(),
+            Some(ErrorCode::SeedsTooLong.into()),
+        );
+    }
+
+    #[test]
+    fn encodes_nonce_instructions() {
+        assert_eq!(
+            AdvanceNonceAccount::instruction_data().as_slice(),
+            [4, 0, 0, 0]
+        );
+        assert_eq!(
+            WithdrawNonceAccount::instruction_data(7).as_slice(),
+            encode::<12>(&[&[5, 0, 0, 0], &7u64.to_le_bytes()]),
+        );
+        assert_eq!(
+            InitializeNonceAccount::instruction_data(&BASE).as_slice(),
+            encode::<36>(&[&[6, 0, 0, 0], BASE.as_ref()]),
+        );
+        assert_eq!(
+            AuthorizeNonceAccount::instruction_data(&OWNER).as_slice(),
+            encode::<36>(&[&[7, 0, 0, 0], OWNER.as_ref()]),
+        );
+        assert_eq!(
+            UpgradeNonceAccount::instruction_data().as_slice(),
+            [12, 0, 0, 0]
+        );
+    }
 }
@@ -1,50 +1,21 @@
 // Copyright (c) 2026, Arcane Labs <dev@arcane.fi>
 // SPDX-License-Identifier: Apache-2.0
 
-use hayabusa_cpi::{CheckProgramId, CpiCtx};
+use hayabusa_cpi::{CheckProgramId, CpiCtx, CpiData, CpiField};
+use hayabusa_cpi_accounts_derive::CpiAccounts;
 use hayabusa_errors::Result;
 use solana_account_view::AccountView;
 use solana_address::Address;
-use solana_instruction_view::{
-    cpi::{invoke, invoke_signed},
-    InstructionAccount, InstructionView,
-};
+use solana_instruction_view::{InstructionAccount, InstructionView};
 
+#[derive(CpiAccounts)]
+#[cpi(program = crate::ID, discriminator = [2, 0, 0, 0], data(lamports: u64))]
 pub struct Transfer<'ix> {
     /// Funding account
+    #[writable]
+    #[signer]
     pub from: &'ix AccountView,
     /// Recipient account
+    #[writable]
     pub to: &'ix AccountView,
 }
-
-impl CheckProgramId for Transfer<'_> {
-    const ID: Address = crate::ID;
-}
-
-#[inline]
-pub fn transfer<'ix>(cpi_ctx: CpiCtx<'ix, '_, '_, '_, Transfer<'ix>>, lamports: u64) -> Result<()> {
-    let account_views = [cpi_ctx.from, cpi_ctx.to];
-    let instruction_accounts = [
-        InstructionAccount::writable_signer(cpi_ctx.from.address()),
-        InstructionAccount::writable(cpi_ctx.to.address()),
-    ];
-
-    // ix data
-    // - [0..4]: discriminator
-    // - [4..12]: lamports amount
-    let mut ix_data = [0; 12];
-    ix_data[0] = 2;
-    ix_data[4..12].copy_from_slice(&lamports.to_le_bytes());
-
-    let instruction = InstructionView {
-        program_id: &crate::ID,
-        accounts: &instruction_accounts,
-        data: &ix_data,
-    };
-
-    if let Some(signers) = cpi_ctx.signers {
-        invoke_signed(&instruction, &account_views, signers)
-    } else {
-        invoke(&instruction, &account_views)
-    }
-}
@@ -0,0 +1,28 @@
+// Copyright (c) 2026, Arcane Labs <dev@arcane.fi>
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::AddressSeed;
+use hayabusa_cpi::{CheckProgramId, CpiCtx, CpiData, CpiField};
+use hayabusa_cpi_accounts_derive::CpiAccounts;
+use hayabusa_errors::Result;
+use solana_account_view::AccountView;
+use solana_address::Address;
+use solana_instruction_view::{InstructionAccount, InstructionView};
+
+#[derive(CpiAccounts)]
+#[cpi(
+    program = crate::ID,
+    discriminator = [11, 0, 0, 0],
+    data(lamports: u64, from_seed: AddressSeed<'_>, from_owner: &Address),
+)]
+pub struct TransferWithSeed<'ix> {
+    /// Funding account, derived from `base` and the seed
+    #[writable]
+    pub from: &'ix AccountView,
+    /// Base account
+    #[signer]
+    pub base: &'ix AccountView,
+    /// Recipient account
+    #[writable]
+    pub to: &'ix AccountView,
+}
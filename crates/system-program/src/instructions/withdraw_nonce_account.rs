@@ -0,0 +1,27 @@
+// Copyright (c) 2026, Arcane Labs <dev@arcane.fi>
+// SPDX-License-Identifier: Apache-2.0
+
+use hayabusa_cpi::{CheckProgramId, CpiCtx, CpiData, CpiField};
+use hayabusa_cpi_accounts_derive::CpiAccounts;
+use hayabusa_errors::Result;
+use solana_account_view::AccountView;
+use solana_address::Address;
+use solana_instruction_view::{InstructionAccount, InstructionView};
+
+#[derive(CpiAccounts)]
+#[cpi(program = crate::ID, discriminator = [5, 0, 0, 0], data(lamports: u64))]
+pub struct WithdrawNonceAccount<'ix> {
+    /// Nonce account
+    #[writable]
+    pub nonce_account: &'ix AccountView,
+    /// Recipient account
+    #[writable]
+    pub to: &'ix AccountView,
+    /// RecentBlockhashes sysvar
+    pub recent_blockhashes_sysvar: &'ix AccountView,
+    /// Rent sysvar
+    pub rent_sysvar: &'ix AccountView,
+    /// Nonce authority
+    #[signer]
+    pub nonce_authority: &'ix AccountView,
+}
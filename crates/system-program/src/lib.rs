@@ -3,6 +3,9 @@
 
 #![no_std]
 
+mod address_seed;
 pub mod instructions;
 
+pub use address_seed::*;
+
 solana_address::declare_id!("11111111111111111111111111111111");
@@ -86,7 +86,7 @@ impl Clock {
     ///
     /// This method performs a check on the account view address.
     #[inline]
-    pub fn from_account_view(account_view: &AccountView) -> Result<Ref<Clock>> {
+    pub fn from_account_view(account_view: &AccountView) -> Result<Ref<'_, Clock>> {
         if unlikely(account_view.address() != &CLOCK_ID) {
             return Err(ProgramError::InvalidArgument);
         }
@@ -66,7 +66,7 @@ where
     pub unsafe fn deserialize_instruction_unchecked(
         &self,
         index: usize,
-    ) -> IntrospectedInstruction {
+    ) -> IntrospectedInstruction<'_> {
         let offset = *(self
             .data
             .as_ptr()
@@ -80,7 +80,7 @@ where
     pub fn load_instruction_at(
         &self,
         index: usize,
-    ) -> Result<IntrospectedInstruction, ProgramError> {
+    ) -> Result<IntrospectedInstruction<'_>, ProgramError> {
         if index >= self.num_instructions() {
             return Err(ProgramError::InvalidInstructionData);
         }
@@ -95,7 +95,7 @@ where
     pub fn get_instruction_relative(
         &self,
         index_relative_to_current: i64,
-    ) -> Result<IntrospectedInstruction, ProgramError> {
+    ) -> Result<IntrospectedInstruction<'_>, ProgramError> {
         let current_index = self.load_current_index() as i64;
         let index = current_index.saturating_add(index_relative_to_current);
 
@@ -90,7 +90,7 @@ impl Rent {
     ///
     /// This method performs a check on the account view key.
     #[inline]
-    pub fn from_account_view(account_view: &AccountView) -> Result<Ref<Rent>> {
+    pub fn from_account_view(account_view: &AccountView) -> Result<Ref<'_, Rent>> {
         if unlikely(account_view.address() != &RENT_ID) {
             return Err(ProgramError::InvalidArgument);
         }
@@ -0,0 +1,240 @@
+// Copyright (c) 2026, Arcane Labs <dev@arcane.fi>
+// SPDX-License-Identifier: Apache-2.0
+
+//! A program that exercises the `#[program]` codegen end to end, from the client instruction
+//! builders through the generated dispatcher.
+
+#![no_std]
+#![allow(unexpected_cfgs)]
+
+use hayabusa::prelude::*;
+
+declare_id!("Hi7uM9EZVHwZCy7pxEUMmY36LG79iNK9vHuYZLWoPTLG");
+
+#[program(event_cpi)]
+mod test_program {
+    use super::*;
+
+    fn emit_value<'ix>(ctx: Ctx<'ix, EmitValue<'ix>>, value: u64) -> Result<()> {
+        emit_cpi!(ctx, ValueLogged { value })
+    }
+
+    fn double<'ix>(_ctx: Ctx<'ix, Caller<'ix>>, value: u64) -> Result<u64> {
+        value.checked_mul(2).ok_or(ProgramError::ArithmeticOverflow)
+    }
+
+    #[execute]
+    fn execute<'ix>(_ctx: Ctx<'ix, Caller<'ix>>, amount: u64) -> Result<()> {
+        require_neq!(amount, 0, ProgramError::InvalidArgument);
+
+        Ok(())
+    }
+
+    #[instruction(interface = "hayabusa-test-interface:ping")]
+    fn ping<'ix>(_ctx: Ctx<'ix, Caller<'ix>>) -> Result<()> {
+        Ok(())
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use crate::instruction::{DoubleIx, ExecuteIx, PingIx};
+        use account_view::{RuntimeAccount, NOT_BORROWED};
+        use sha2::{Digest, Sha256};
+
+        fn account(address: Address, is_signer: bool, executable: bool) -> RuntimeAccount {
+            RuntimeAccount {
+                borrow_state: NOT_BORROWED,
+                is_signer: is_signer as u8,
+                executable: executable as u8,
+                address,
+                ..Default::default()
+            }
+        }
+
+        #[test]
+        fn event_authority() {
+            assert_eq!(
+                EVENT_AUTHORITY,
+                Address::find_program_address(&[EVENT_AUTHORITY_SEED], &crate::ID)
+            );
+            assert_eq!(event_authority_address(&crate::ID), EVENT_AUTHORITY.0);
+
+            let (_, metas, _) = client::emit_value(
+                &EmitValueAccounts {
+                    event_authority: EVENT_AUTHORITY.0,
+                    program: crate::ID,
+                },
+                7,
+            );
+            assert_eq!(metas[0].pubkey, EVENT_AUTHORITY.0);
+            assert_eq!(metas[1].pubkey, crate::ID);
+        }
+
+        #[test]
+        fn emits_event_cpi() {
+            let mut authority = account(EVENT_AUTHORITY.0, false, false);
+            let mut program = account(crate::ID, false, true);
+            // SAFETY: the accounts carry no data
+            let views = unsafe {
+                [
+                    AccountView::new_unchecked(&mut authority),
+                    AccountView::new_unchecked(&mut program),
+                ]
+            };
+
+            let (_, _, data) = client::emit_value(
+                &EmitValueAccounts {
+                    event_authority: EVENT_AUTHORITY.0,
+                    program: crate::ID,
+                },
+                7,
+            );
+            assert_eq!(dispatcher(&crate::ID, &views, &data), Ok(()));
+
+            // `emit_cpi!` on another program's account fails the `SelfProgram` check
+            let mut other = account(Address::new_from_array([9; 32]), false, true);
+            // SAFETY: the account carries no data
+            let other = unsafe { AccountView::new_unchecked(&mut other) };
+            let [authority, _] = views;
+            assert_eq!(
+                dispatcher(&crate::ID, &[authority, other], &data),
+                Err(ProgramError::IncorrectProgramId)
+            );
+        }
+
+        #[test]
+        fn dispatches_event_cpi() {
+            // the self-invocation `emit_cpi!` makes: event tag, then the raw event
+            let mut data = [0u8; 24];
+            data[..8].copy_from_slice(&EVENT_IX_TAG_LE);
+            data[8..16].copy_from_slice(ValueLogged::DISCRIMINATOR);
+            data[16..].copy_from_slice(&7u64.to_le_bytes());
+
+            assert_eq!(data[..8], [0xe4, 0x45, 0xa5, 0x2e, 0x51, 0xcb, 0x9a, 0x1d]);
+            assert_eq!(ValueLogged::decode(&data[8..]).unwrap().value, 7);
+
+            let mut authority = account(EVENT_AUTHORITY.0, true, false);
+            // SAFETY: the account carries no data
+            let views = [unsafe { AccountView::new_unchecked(&mut authority) }];
+            assert_eq!(dispatcher(&crate::ID, &views, &data), Ok(()));
+
+            // nothing past the tag is decoded
+            assert_eq!(dispatcher(&crate::ID, &views, &data[..8]), Ok(()));
+
+            assert_eq!(
+                dispatcher(&crate::ID, &[], &data),
+                Err(ProgramError::NotEnoughAccountKeys)
+            );
+
+            let mut unsigned = account(EVENT_AUTHORITY.0, false, false);
+            // SAFETY: the account carries no data
+            let unsigned = unsafe { AccountView::new_unchecked(&mut unsigned) };
+            assert_eq!(
+                dispatcher(&crate::ID, &[unsigned], &data),
+                Err(ProgramError::MissingRequiredSignature)
+            );
+
+            let mut impostor = account(Address::new_from_array([9; 32]), true, false);
+            // SAFETY: the account carries no data
+            let impostor = unsafe { AccountView::new_unchecked(&mut impostor) };
+            assert_eq!(
+                dispatcher(&crate::ID, &[impostor], &data),
+                Err(ErrorCode::InvalidAccount.into())
+            );
+        }
+
+        fn caller() -> CallerAccounts {
+            CallerAccounts {
+                user: Address::new_from_array([1; 32]),
+            }
+        }
+
+        #[test]
+        fn return_values() {
+            let mut user = account(caller().user, true, false);
+            // SAFETY: the account carries no data
+            let views = [unsafe { AccountView::new_unchecked(&mut user) }];
+
+            // the handler's `u64` becomes return data; the dispatcher itself returns `()`
+            let (_, _, data) = client::double(&caller(), 21);
+            assert_eq!(data[..8], *DoubleIx::DISCRIMINATOR);
+            assert_eq!(dispatcher(&crate::ID, &views, &data), Ok(()));
+
+            let (_, _, data) = client::double(&caller(), u64::MAX);
+            assert_eq!(
+                dispatcher(&crate::ID, &views, &data),
+                Err(ProgramError::ArithmeticOverflow)
+            );
+
+            // the CPI helper reads the value back through `invoke_with_return`, and off-chain
+            // there is never any return data to read
+            let mut program = account(crate::ID, false, true);
+            // SAFETY: the account carries no data
+            let program = unsafe { AccountView::new_unchecked(&mut program) };
+            let cpi_ctx =
+                CpiCtx::try_new_without_signer(&program, CallerCpiAccounts { user: &views[0] })
+                    .unwrap();
+            assert_eq!(
+                cpi::double(cpi_ctx, 21),
+                Err(ErrorCode::InvalidReturnData.into())
+            );
+        }
+
+        #[test]
+        fn execute_instruction() {
+            // sha256("spl-transfer-hook-interface:execute")[..8]
+            assert_eq!(
+                ExecuteIx::DISCRIMINATOR,
+                &[105, 37, 101, 197, 75, 251, 102, 26]
+            );
+
+            let (_, _, data) = client::execute(&caller(), 3);
+            assert_eq!(data[..8], [105, 37, 101, 197, 75, 251, 102, 26]);
+            assert_eq!(data[8..], 3u64.to_le_bytes());
+
+            let mut user = account(caller().user, true, false);
+            // SAFETY: the account carries no data
+            let views = [unsafe { AccountView::new_unchecked(&mut user) }];
+            assert_eq!(dispatcher(&crate::ID, &views, &data), Ok(()));
+
+            let (_, _, data) = client::execute(&caller(), 0);
+            assert_eq!(
+                dispatcher(&crate::ID, &views, &data),
+                Err(ProgramError::InvalidArgument)
+            );
+        }
+
+        #[test]
+        fn interface_instruction() {
+            assert_eq!(
+                PingIx::DISCRIMINATOR,
+                &Sha256::digest(b"hayabusa-test-interface:ping")[..8]
+            );
+
+            let (_, _, data) = client::ping(&caller());
+            assert_eq!(data, PingIx::DISCRIMINATOR);
+
+            let mut user = account(caller().user, true, false);
+            // SAFETY: the account carries no data
+            let views = [unsafe { AccountView::new_unchecked(&mut user) }];
+            assert_eq!(dispatcher(&crate::ID, &views, &data), Ok(()));
+        }
+    }
+}
+
+#[derive(FromAccountViews)]
+pub struct EmitValue<'ix> {
+    pub event_authority: EventAuthority<'ix>,
+    pub program: Program<'ix, SelfProgram>,
+}
+
+#[derive(FromAccountViews)]
+pub struct Caller<'ix> {
+    pub user: Signer<'ix>,
+}
+
+#[event]
+pub struct ValueLogged {
+    pub value: u64,
+}
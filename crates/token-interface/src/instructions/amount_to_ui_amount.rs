@@ -0,0 +1,32 @@
+// Copyright (c) 2026, Arcane Labs <dev@arcane.fi>
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::TokenInterface;
+use hayabusa_accounts::ProgramIds;
+use hayabusa_common::{AccountView, Address};
+use hayabusa_cpi::{CheckProgramId, CpiCtx, CpiData, CpiField};
+use hayabusa_cpi_accounts_derive::CpiAccounts;
+use hayabusa_errors::{ErrorCode, Result};
+use solana_instruction_view::{InstructionAccount, InstructionView};
+
+#[derive(CpiAccounts)]
+#[cpi(programs = TokenInterface::IDS, discriminator = [23], data(amount: u64))]
+pub struct AmountToUiAmount<'ix> {
+    /// Mint account
+    pub mint: &'ix AccountView,
+}
+
+/// Reads the UI amount `program_id` returned from `amount_to_ui_amount` into `buf`.
+///
+/// # Errors
+/// Returns `ErrorCode::InvalidReturnData` if the return data was not set by `program_id`,
+/// does not fit in `buf` or is not UTF-8.
+#[inline]
+pub fn read_ui_amount<'a>(program_id: &Address, buf: &'a mut [u8]) -> Result<&'a str> {
+    match hayabusa_syscalls::get_return_data(buf) {
+        Some((returned_by, len)) if returned_by == *program_id && len <= buf.len() => {
+            core::str::from_utf8(&buf[..len]).map_err(|_| ErrorCode::InvalidReturnData.into())
+        }
+        _ => Err(ErrorCode::InvalidReturnData.into()),
+    }
+}
@@ -0,0 +1,27 @@
+// Copyright (c) 2026, Arcane Labs <dev@arcane.fi>
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{TokenInterface, MAX_MULTISIG_SIGNERS};
+use hayabusa_accounts::ProgramIds;
+use hayabusa_common::{AccountView, Address};
+use hayabusa_cpi::{CheckProgramId, CpiCtx, CpiData, CpiField};
+use hayabusa_cpi_accounts_derive::CpiAccounts;
+use hayabusa_errors::{ProgramError, Result};
+use solana_instruction_view::{InstructionAccount, InstructionView};
+
+#[derive(CpiAccounts)]
+#[cpi(programs = TokenInterface::IDS, discriminator = [4], data(amount: u64))]
+pub struct Approve<'ix> {
+    /// Source account
+    #[writable]
+    pub source: &'ix AccountView,
+    /// Delegate account
+    pub delegate: &'ix AccountView,
+    /// Source account owner
+    #[signer(multisig)]
+    pub authority: &'ix AccountView,
+    /// Multisig signers when the authority is a `Multisig` account, empty otherwise
+    #[remaining(max = MAX_MULTISIG_SIGNERS)]
+    #[signer]
+    pub multisig_signers: &'ix [&'ix AccountView],
+}
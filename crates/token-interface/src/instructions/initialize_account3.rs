@@ -0,0 +1,20 @@
+// Copyright (c) 2026, Arcane Labs <dev@arcane.fi>
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::TokenInterface;
+use hayabusa_accounts::ProgramIds;
+use hayabusa_common::{AccountView, Address};
+use hayabusa_cpi::{CheckProgramId, CpiCtx, CpiData, CpiField};
+use hayabusa_cpi_accounts_derive::CpiAccounts;
+use hayabusa_errors::Result;
+use solana_instruction_view::{InstructionAccount, InstructionView};
+
+#[derive(CpiAccounts)]
+#[cpi(programs = TokenInterface::IDS, discriminator = [18], data(owner_pk: &Address))]
+pub struct InitializeAccount3<'ix> {
+    /// New account
+    #[writable]
+    pub account: &'ix AccountView,
+    /// Mint account
+    pub mint: &'ix AccountView,
+}
@@ -0,0 +1,21 @@
+// Copyright (c) 2026, Arcane Labs <dev@arcane.fi>
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{TokenInterface, MAX_MULTISIG_SIGNERS};
+use hayabusa_accounts::ProgramIds;
+use hayabusa_common::{AccountView, Address};
+use hayabusa_cpi::{CheckProgramId, CpiCtx, CpiData, CpiField};
+use hayabusa_cpi_accounts_derive::CpiAccounts;
+use hayabusa_errors::{ProgramError, Result};
+use solana_instruction_view::{InstructionAccount, InstructionView};
+
+#[derive(CpiAccounts)]
+#[cpi(programs = TokenInterface::IDS, discriminator = [19], data(m: u8))]
+pub struct InitializeMultisig2<'ix> {
+    /// Multisig account
+    #[writable]
+    pub multisig: &'ix AccountView,
+    /// Signer accounts, `m` of which are required
+    #[remaining(max = MAX_MULTISIG_SIGNERS)]
+    pub signers: &'ix [&'ix AccountView],
+}
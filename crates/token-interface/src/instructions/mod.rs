@@ -0,0 +1,103 @@
+// Copyright (c) 2026, Arcane Labs <dev@arcane.fi>
+// SPDX-License-Identifier: Apache-2.0
+
+pub mod amount_to_ui_amount;
+pub mod approve;
+pub mod approve_checked;
+pub mod burn;
+pub mod burn_checked;
+pub mod close_account;
+pub mod freeze_account;
+pub mod initialize_account3;
+pub mod initialize_immutable_owner;
+pub mod initialize_mint2;
+pub mod initialize_multisig2;
+pub mod mint_to;
+pub mod mint_to_checked;
+pub mod revoke;
+pub mod set_authority;
+pub mod sync_native;
+pub mod thaw_account;
+pub mod transfer;
+pub mod transfer_checked;
+pub mod ui_amount_to_amount;
+
+pub use amount_to_ui_amount::*;
+pub use approve::*;
+pub use approve_checked::*;
+pub use burn::*;
+pub use burn_checked::*;
+pub use close_account::*;
+pub use freeze_account::*;
+pub use initialize_account3::*;
+pub use initialize_immutable_owner::*;
+pub use initialize_mint2::*;
+pub use initialize_multisig2::*;
+pub use mint_to::*;
+pub use mint_to_checked::*;
+pub use revoke::*;
+pub use set_authority::*;
+pub use sync_native::*;
+pub use thaw_account::*;
+pub use transfer::*;
+pub use transfer_checked::*;
+pub use ui_amount_to_amount::*;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::UiAmount;
+    use hayabusa_common::Address;
+    use hayabusa_cpi::CheckProgramId;
+
+    #[test]
+    fn encodes_instruction_data() {
+        let amount = 0x0102u64.to_le_bytes();
+
+        assert_eq!(Approve::instruction_data(0x0102).as_slice(), [&[4][..], &amount].concat());
+        assert_eq!(
+            ApproveChecked::instruction_data(0x0102, 6).as_slice(),
+            [&[13][..], &amount, &[6]].concat(),
+        );
+        assert_eq!(Revoke::instruction_data().as_slice(), [5]);
+        assert_eq!(CloseAccount::instruction_data().as_slice(), [9]);
+        assert_eq!(FreezeAccount::instruction_data().as_slice(), [10]);
+        assert_eq!(SyncNative::instruction_data().as_slice(), [17]);
+        assert_eq!(InitializeMultisig2::instruction_data(2).as_slice(), [19, 2]);
+        assert_eq!(InitializeImmutableOwner::instruction_data().as_slice(), [22]);
+        assert_eq!(
+            AmountToUiAmount::instruction_data(0x0102).as_slice(),
+            [&[23][..], &amount].concat(),
+        );
+        assert_eq!(
+            UiAmountToAmount::instruction_data(UiAmount::try_new("2.58").unwrap()).as_slice(),
+            [24, b'2', b'.', b'5', b'8'],
+        );
+
+        let authority = Address::new_from_array([3; 32]);
+        assert_eq!(
+            SetAuthority::instruction_data(AuthorityType::CloseAccount, Some(&authority))
+                .as_slice(),
+            [&[6, 3, 1][..], authority.as_ref()].concat(),
+        );
+        assert_eq!(
+            SetAuthority::instruction_data(AuthorityType::MintTokens, None).as_slice(),
+            [6, 0, 0],
+        );
+    }
+
+    #[test]
+    fn accepts_either_token_program() {
+        assert!(TransferChecked::check_program_id(&hayabusa_token::ID).is_ok());
+        assert!(TransferChecked::check_program_id(&hayabusa_token2022::ID).is_ok());
+        assert!(TransferChecked::check_program_id(&Address::default()).is_err());
+
+        // extension instructions keep rejecting Token
+        assert!(
+            hayabusa_token2022::instructions::InitializeMintCloseAuthority::check_program_id(
+                &hayabusa_token::ID
+            )
+            .is_err()
+        );
+    }
+}
@@ -0,0 +1,48 @@
+// Copyright (c) 2026, Arcane Labs <dev@arcane.fi>
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{TokenInterface, MAX_MULTISIG_SIGNERS};
+use core::mem::MaybeUninit;
+use hayabusa_accounts::ProgramIds;
+use hayabusa_common::{AccountView, Address};
+use hayabusa_cpi::{CheckProgramId, CpiCtx, CpiData, CpiField};
+use hayabusa_cpi_accounts_derive::CpiAccounts;
+use hayabusa_errors::{ProgramError, Result};
+use solana_instruction_view::{InstructionAccount, InstructionView};
+
+#[repr(u8)]
+#[derive(Clone, Copy)]
+pub enum AuthorityType {
+    MintTokens = 0,
+    FreezeAccount = 1,
+    AccountOwner = 2,
+    CloseAccount = 3,
+}
+
+impl CpiField for AuthorityType {
+    const MAX_SIZE: usize = 1;
+
+    #[inline(always)]
+    fn write_cpi_field(&self, bytes: &mut [MaybeUninit<u8>]) -> usize {
+        (*self as u8).write_cpi_field(bytes)
+    }
+}
+
+#[derive(CpiAccounts)]
+#[cpi(
+    programs = TokenInterface::IDS,
+    discriminator = [6],
+    data(authority_type: AuthorityType, new_authority: Option<&Address>),
+)]
+pub struct SetAuthority<'ix> {
+    /// Account (Mint or Token)
+    #[writable]
+    pub account: &'ix AccountView,
+    /// Authority of the account
+    #[signer(multisig)]
+    pub authority: &'ix AccountView,
+    /// Multisig signers when the authority is a `Multisig` account, empty otherwise
+    #[remaining(max = MAX_MULTISIG_SIGNERS)]
+    #[signer]
+    pub multisig_signers: &'ix [&'ix AccountView],
+}
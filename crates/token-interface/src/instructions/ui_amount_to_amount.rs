@@ -0,0 +1,22 @@
+// Copyright (c) 2026, Arcane Labs <dev@arcane.fi>
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{TokenInterface, UiAmount};
+use hayabusa_accounts::ProgramIds;
+use hayabusa_common::{AccountView, Address};
+use hayabusa_cpi::{CheckProgramId, CpiCtx, CpiData, CpiField};
+use hayabusa_cpi_accounts_derive::CpiAccounts;
+use hayabusa_errors::Result;
+use solana_instruction_view::{InstructionAccount, InstructionView};
+
+#[derive(CpiAccounts)]
+#[cpi(
+    programs = TokenInterface::IDS,
+    discriminator = [24],
+    returns = u64,
+    data(ui_amount: UiAmount<'_>),
+)]
+pub struct UiAmountToAmount<'ix> {
+    /// Mint account
+    pub mint: &'ix AccountView,
+}
@@ -3,11 +3,37 @@
 
 #![no_std]
 
+//! Instructions shared by Token and Token-2022, which `hayabusa-token` and
+//! `hayabusa-token2022` re-export.
+//!
+//! Their CPI contexts accept either program, so a handler taking an
+//! `Interface<'ix, TokenInterface>` passes it as is and invokes whichever program the caller
+//! provided:
+//!
+//! ```ignore
+//! let ctx = CpiCtx::try_new_without_signer(
+//!     &token_program,
+//!     TransferChecked { from, mint, to, authority, multisig_signers: &[] },
+//! )?;
+//! transfer_checked(ctx, amount, decimals)?;
+//! ```
+
+pub mod instructions;
+mod ui_amount;
+
+pub use ui_amount::*;
+
 use hayabusa_accounts::ProgramIds;
-use hayabusa_common::Address;
+use hayabusa_common::{address, Address};
+
+/// Most signers of a `Multisig` account.
+pub const MAX_MULTISIG_SIGNERS: usize = 11;
 
 pub struct TokenInterface;
 
 impl ProgramIds for TokenInterface {
-    const IDS: &'static [Address] = &[hayabusa_token::ID, hayabusa_token2022::ID];
+    const IDS: &'static [Address] = &[
+        address!("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"),
+        address!("TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb"),
+    ];
 }
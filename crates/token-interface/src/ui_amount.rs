@@ -0,0 +1,43 @@
+// Copyright (c) 2026, Arcane Labs <dev@arcane.fi>
+// SPDX-License-Identifier: Apache-2.0
+
+use core::mem::MaybeUninit;
+use hayabusa_cpi::CpiField;
+use hayabusa_errors::{ProgramError, Result};
+use hayabusa_utility::write_uninit_bytes;
+
+/// Longest UI amount: the 20 digits of `u64::MAX`, a decimal point and up to 255 decimals.
+pub const MAX_UI_AMOUNT_LEN: usize = 20 + 1 + u8::MAX as usize;
+
+/// Decimal amount string taken by `ui_amount_to_amount`, e.g. `"1.5"`.
+///
+/// Encoded as the raw UTF-8 bytes, without a length prefix.
+#[derive(Clone, Copy)]
+pub struct UiAmount<'a>(&'a str);
+
+impl<'a> UiAmount<'a> {
+    /// Returns `ProgramError::InvalidArgument` if `amount` exceeds `MAX_UI_AMOUNT_LEN` bytes.
+    #[inline(always)]
+    pub fn try_new(amount: &'a str) -> Result<Self> {
+        if amount.len() > MAX_UI_AMOUNT_LEN {
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        Ok(Self(amount))
+    }
+
+    #[inline(always)]
+    pub fn as_str(&self) -> &'a str {
+        self.0
+    }
+}
+
+impl CpiField for UiAmount<'_> {
+    const MAX_SIZE: usize = MAX_UI_AMOUNT_LEN;
+
+    #[inline(always)]
+    fn write_cpi_field(&self, bytes: &mut [MaybeUninit<u8>]) -> usize {
+        write_uninit_bytes(bytes, self.0.as_bytes());
+        self.0.len()
+    }
+}
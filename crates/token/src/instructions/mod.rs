@@ -1,23 +1,5 @@
 // Copyright (c) 2026, Arcane Labs <dev@arcane.fi>
 // SPDX-License-Identifier: Apache-2.0
 
-pub mod burn;
-pub mod burn_checked;
-pub mod initialize_account3;
-pub mod initialize_mint2;
-pub mod mint_to;
-pub mod mint_to_checked;
-pub mod set_authority;
-pub mod thaw_account;
-pub mod transfer;
-pub mod transfer_checked;
-
-pub use burn::*;
-pub use burn_checked::*;
-pub use initialize_account3::*;
-pub use initialize_mint2::*;
-pub use mint_to::*;
-pub use mint_to_checked::*;
-pub use set_authority::*;
-pub use transfer::*;
-pub use transfer_checked::*;
+// every Token instruction is shared with Token-2022; their CPI contexts accept either program
+pub use hayabusa_token_interface::instructions::*;
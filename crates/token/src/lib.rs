@@ -6,6 +6,8 @@
 pub mod instructions;
 pub mod state;
 
+pub use hayabusa_token_interface::{UiAmount, MAX_UI_AMOUNT_LEN};
+
 hayabusa_common::declare_id!("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA");
 
 use hayabusa_accounts::ProgramId;
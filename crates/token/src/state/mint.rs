@@ -46,7 +46,7 @@ impl Deserialize for Mint {}
 /// SAFETY:
 /// Account data length is validated, account info buffer guaranteed aligned so it is safe to cast from raw ptr.
 unsafe impl RawZcDeserialize for Mint {
-    fn try_deserialize_raw(account_view: &AccountView) -> Result<Ref<Self>> {
+    fn try_deserialize_raw(account_view: &AccountView) -> Result<Ref<'_, Self>> {
         if unlikely(account_view.data_len() != Self::LEN) {
             error_msg!(
                 "Mint::try_deserialize_raw: data length mismatch",
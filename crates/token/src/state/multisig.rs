@@ -8,7 +8,7 @@ use hayabusa_ser::{
 };
 use hayabusa_utility::{error_msg, hint::unlikely, OwnerProgram};
 
-pub const MAX_MULTISIG_SIGNERS: usize = 11;
+pub use hayabusa_token_interface::MAX_MULTISIG_SIGNERS;
 
 /// Multisignature data.
 #[repr(C)]
@@ -31,7 +31,7 @@ impl Zc for Multisig {}
 impl Deserialize for Multisig {}
 
 unsafe impl RawZcDeserialize for Multisig {
-    fn try_deserialize_raw(account_view: &AccountView) -> hayabusa_errors::Result<Ref<Self>> {
+    fn try_deserialize_raw(account_view: &AccountView) -> hayabusa_errors::Result<Ref<'_, Self>> {
         if unlikely(account_view.data_len() != Self::LEN) {
             error_msg!(
                 "Multisig::try_deserialize_raw: data length mismatch",
@@ -60,7 +60,7 @@ impl Deserialize for TokenAccount {}
 
 unsafe impl RawZcDeserialize for TokenAccount {
     #[inline]
-    fn try_deserialize_raw(account_view: &AccountView) -> Result<Ref<Self>> {
+    fn try_deserialize_raw(account_view: &AccountView) -> Result<Ref<'_, Self>> {
         if unlikely(account_view.data_len() != Self::LEN) {
             error_msg!(
                 "TokenAccount::try_deserialize_raw: data length mismatch",
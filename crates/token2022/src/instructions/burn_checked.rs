@@ -1,64 +0,0 @@
-// Copyright (c) 2026, Arcane Labs <dev@arcane.fi>
-// SPDX-License-Identifier: Apache-2.0
-
-use core::slice::from_raw_parts;
-use hayabusa_common::{AccountView, Address};
-use hayabusa_cpi::{CheckProgramId, CpiCtx};
-use hayabusa_errors::Result;
-use hayabusa_utility::{write_uninit_bytes, UNINIT_BYTE};
-use solana_instruction_view::{
-    cpi::{invoke, invoke_signed},
-    InstructionAccount, InstructionView,
-};
-
-pub struct BurnChecked<'ix> {
-    /// The account being burned from
-    pub burn_account: &'ix AccountView,
-    /// The mint account
-    pub mint: &'ix AccountView,
-    /// The authority account
-    pub authority: &'ix AccountView,
-}
-
-impl CheckProgramId for BurnChecked<'_> {
-    const ID: Address = crate::ID;
-}
-
-const DISCRIMINATOR: [u8; 1] = [15];
-
-#[inline(always)]
-pub fn burn_checked<'ix>(
-    cpi_ctx: CpiCtx<'ix, '_, '_, '_, BurnChecked<'ix>>,
-    amount: u64,
-    decimals: u8,
-) -> Result<()> {
-    let account_views = [cpi_ctx.burn_account, cpi_ctx.mint, cpi_ctx.authority];
-
-    let instruction_accounts = [
-        InstructionAccount::writable(cpi_ctx.burn_account.address()),
-        InstructionAccount::writable(cpi_ctx.mint.address()),
-        InstructionAccount::readonly_signer(cpi_ctx.authority.address()),
-    ];
-
-    // ix data layout
-    // - [0]: discriminator
-    // - [1..9]: amount
-    // - [9]: decimals
-    let mut ix_data = [UNINIT_BYTE; 10];
-
-    write_uninit_bytes(&mut ix_data, &DISCRIMINATOR);
-    write_uninit_bytes(&mut ix_data[1..9], &amount.to_le_bytes());
-    write_uninit_bytes(&mut ix_data[9..], &[decimals]);
-
-    let instruction_view = InstructionView {
-        program_id: &crate::ID,
-        accounts: &instruction_accounts,
-        data: unsafe { from_raw_parts(ix_data.as_ptr() as _, 10) },
-    };
-
-    if let Some(signers) = cpi_ctx.signers {
-        invoke_signed(&instruction_view, &account_views, signers)
-    } else {
-        invoke(&instruction_view, &account_views)
-    }
-}
@@ -0,0 +1,23 @@
+// Copyright (c) 2026, Arcane Labs <dev@arcane.fi>
+// SPDX-License-Identifier: Apache-2.0
+
+use hayabusa_common::{AccountView, Address};
+use hayabusa_cpi::{CheckProgramId, CpiCtx, CpiData};
+use hayabusa_cpi_accounts_derive::CpiAccounts;
+use hayabusa_errors::{ProgramError, Result};
+use solana_instruction_view::{InstructionAccount, InstructionView};
+
+/// Most token accounts harvested by one `harvest_withheld_tokens_to_mint` call.
+pub const MAX_HARVEST_SOURCES: usize = 32;
+
+#[derive(CpiAccounts)]
+#[cpi(program = crate::ID, discriminator = [26, 4])]
+pub struct HarvestWithheldTokensToMint<'ix> {
+    /// Mint account
+    #[writable]
+    pub mint: &'ix AccountView,
+    /// Token accounts to harvest the withheld fees of
+    #[remaining(max = MAX_HARVEST_SOURCES)]
+    #[writable]
+    pub sources: &'ix [&'ix AccountView],
+}
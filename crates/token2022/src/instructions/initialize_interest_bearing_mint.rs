@@ -0,0 +1,21 @@
+// Copyright (c) 2026, Arcane Labs <dev@arcane.fi>
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::OptionalNonZeroAddress;
+use hayabusa_common::{AccountView, Address};
+use hayabusa_cpi::{CheckProgramId, CpiCtx, CpiData, CpiField};
+use hayabusa_cpi_accounts_derive::CpiAccounts;
+use hayabusa_errors::Result;
+use solana_instruction_view::{InstructionAccount, InstructionView};
+
+#[derive(CpiAccounts)]
+#[cpi(
+    program = crate::ID,
+    discriminator = [33, 0],
+    data(rate_authority: OptionalNonZeroAddress<'_>, rate: i16),
+)]
+pub struct InitializeInterestBearingMint<'ix> {
+    /// Mint account
+    #[writable]
+    pub mint: &'ix AccountView,
+}
@@ -0,0 +1,21 @@
+// Copyright (c) 2026, Arcane Labs <dev@arcane.fi>
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::OptionalNonZeroAddress;
+use hayabusa_common::{AccountView, Address};
+use hayabusa_cpi::{CheckProgramId, CpiCtx, CpiData, CpiField};
+use hayabusa_cpi_accounts_derive::CpiAccounts;
+use hayabusa_errors::Result;
+use solana_instruction_view::{InstructionAccount, InstructionView};
+
+#[derive(CpiAccounts)]
+#[cpi(
+    program = crate::ID,
+    discriminator = [39, 0],
+    data(authority: OptionalNonZeroAddress<'_>, metadata_address: OptionalNonZeroAddress<'_>),
+)]
+pub struct InitializeMetadataPointer<'ix> {
+    /// Mint account
+    #[writable]
+    pub mint: &'ix AccountView,
+}
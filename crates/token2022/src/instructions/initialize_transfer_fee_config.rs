@@ -0,0 +1,25 @@
+// Copyright (c) 2026, Arcane Labs <dev@arcane.fi>
+// SPDX-License-Identifier: Apache-2.0
+
+use hayabusa_common::{AccountView, Address};
+use hayabusa_cpi::{CheckProgramId, CpiCtx, CpiData, CpiField};
+use hayabusa_cpi_accounts_derive::CpiAccounts;
+use hayabusa_errors::Result;
+use solana_instruction_view::{InstructionAccount, InstructionView};
+
+#[derive(CpiAccounts)]
+#[cpi(
+    program = crate::ID,
+    discriminator = [26, 0],
+    data(
+        transfer_fee_config_authority: Option<&Address>,
+        withdraw_withheld_authority: Option<&Address>,
+        transfer_fee_basis_points: u16,
+        maximum_fee: u64,
+    ),
+)]
+pub struct InitializeTransferFeeConfig<'ix> {
+    /// Mint account
+    #[writable]
+    pub mint: &'ix AccountView,
+}
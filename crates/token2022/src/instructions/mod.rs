@@ -1,24 +1,104 @@
 // Copyright (c) 2026, Arcane Labs <dev@arcane.fi>
 // SPDX-License-Identifier: Apache-2.0
 
-pub mod burn;
-pub mod burn_checked;
-pub mod initialize_account3;
-pub mod initialize_mint2;
-pub mod mint_to;
-pub mod mint_to_checked;
-pub mod set_authority;
-pub mod thaw_account;
-pub mod transfer;
-pub mod transfer_checked;
+pub mod harvest_withheld_tokens_to_mint;
+pub mod initialize_default_account_state;
+pub mod initialize_interest_bearing_mint;
+pub mod initialize_metadata_pointer;
+pub mod initialize_mint_close_authority;
+pub mod initialize_non_transferable_mint;
+pub mod initialize_permanent_delegate;
+pub mod initialize_transfer_fee_config;
+pub mod initialize_transfer_hook;
+pub mod reallocate;
+pub mod set_transfer_fee;
+pub mod transfer_checked_with_fee;
+pub mod update_default_account_state;
+pub mod update_interest_bearing_mint_rate;
+pub mod update_transfer_hook;
+pub mod withdraw_withheld_tokens_from_accounts;
+pub mod withdraw_withheld_tokens_from_mint;
 
-pub use burn::*;
-pub use burn_checked::*;
-pub use initialize_account3::*;
-pub use initialize_mint2::*;
-pub use mint_to::*;
-pub use mint_to_checked::*;
-pub use set_authority::*;
-pub use thaw_account::*;
-pub use transfer::*;
-pub use transfer_checked::*;
+// the instructions shared with Token, whose CPI contexts accept either program
+pub use hayabusa_token_interface::instructions::*;
+
+pub use harvest_withheld_tokens_to_mint::*;
+pub use initialize_default_account_state::*;
+pub use initialize_interest_bearing_mint::*;
+pub use initialize_metadata_pointer::*;
+pub use initialize_mint_close_authority::*;
+pub use initialize_non_transferable_mint::*;
+pub use initialize_permanent_delegate::*;
+pub use initialize_transfer_fee_config::*;
+pub use initialize_transfer_hook::*;
+pub use reallocate::*;
+pub use set_transfer_fee::*;
+pub use transfer_checked_with_fee::*;
+pub use update_default_account_state::*;
+pub use update_interest_bearing_mint_rate::*;
+pub use update_transfer_hook::*;
+pub use withdraw_withheld_tokens_from_accounts::*;
+pub use withdraw_withheld_tokens_from_mint::*;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        state::{account_state::AccountState, extension::ExtensionType},
+        OptionalNonZeroAddress,
+    };
+    use hayabusa_common::Address;
+
+    #[test]
+    fn encodes_extension_instruction_data() {
+        let authority = Address::new_from_array([3; 32]);
+        let program_id = Address::new_from_array([4; 32]);
+        let fee = [&10u16.to_le_bytes()[..], &0x0102u64.to_le_bytes()].concat();
+
+        assert_eq!(
+            InitializeMintCloseAuthority::instruction_data(Some(&authority)).as_slice(),
+            [&[25, 1][..], authority.as_ref()].concat(),
+        );
+        // `COption` authorities shrink to their tag when absent
+        assert_eq!(
+            InitializeTransferFeeConfig::instruction_data(None, Some(&authority), 10, 0x0102)
+                .as_slice(),
+            [&[26, 0, 0, 1][..], authority.as_ref(), &fee].concat(),
+        );
+        assert_eq!(
+            SetTransferFee::instruction_data(10, 0x0102).as_slice(),
+            [&[26, 5][..], &fee].concat(),
+        );
+        // the source count follows the sub-instruction
+        assert_eq!(
+            WithdrawWithheldTokensFromAccounts::instruction_data(3).as_slice(),
+            [26, 3, 3],
+        );
+        assert_eq!(HarvestWithheldTokensToMint::instruction_data().as_slice(), [26, 4]);
+        assert_eq!(
+            InitializeDefaultAccountState::instruction_data(AccountState::Frozen).as_slice(),
+            [28, 0, 2],
+        );
+        // optional non-zero authorities are always 32 bytes
+        assert_eq!(
+            InitializeTransferHook::instruction_data(
+                OptionalNonZeroAddress(None),
+                (&program_id).into(),
+            )
+            .as_slice(),
+            [&[36, 0][..], &[0; 32], program_id.as_ref()].concat(),
+        );
+        assert_eq!(
+            InitializeInterestBearingMint::instruction_data((&authority).into(), -5).as_slice(),
+            [&[33, 0][..], authority.as_ref(), &(-5i16).to_le_bytes()].concat(),
+        );
+        assert_eq!(
+            Reallocate::instruction_data(
+                ExtensionTypes::try_new(&[ExtensionType::MemoTransfer, ExtensionType::CpiGuard])
+                    .unwrap(),
+            )
+            .as_slice(),
+            [29, 8, 0, 11, 0],
+        );
+    }
+}
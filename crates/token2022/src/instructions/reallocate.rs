@@ -0,0 +1,66 @@
+// Copyright (c) 2026, Arcane Labs <dev@arcane.fi>
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::state::{extension::ExtensionType, multisig::MAX_MULTISIG_SIGNERS};
+use core::mem::MaybeUninit;
+use hayabusa_common::{AccountView, Address};
+use hayabusa_cpi::{CheckProgramId, CpiCtx, CpiData, CpiField};
+use hayabusa_cpi_accounts_derive::CpiAccounts;
+use hayabusa_errors::{ProgramError, Result};
+use solana_instruction_view::{InstructionAccount, InstructionView};
+
+/// Most extensions added by one `reallocate` call.
+pub const MAX_REALLOCATE_EXTENSIONS: usize = 16;
+
+/// Extensions to make room for, encoded as consecutive `u16`s.
+#[derive(Clone, Copy)]
+pub struct ExtensionTypes<'a>(&'a [ExtensionType]);
+
+impl<'a> ExtensionTypes<'a> {
+    /// Returns `ProgramError::InvalidArgument` if there are more than `MAX_REALLOCATE_EXTENSIONS`.
+    #[inline(always)]
+    pub fn try_new(extension_types: &'a [ExtensionType]) -> Result<Self> {
+        if extension_types.len() > MAX_REALLOCATE_EXTENSIONS {
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        Ok(Self(extension_types))
+    }
+
+    #[inline(always)]
+    pub fn as_slice(&self) -> &'a [ExtensionType] {
+        self.0
+    }
+}
+
+impl CpiField for ExtensionTypes<'_> {
+    const MAX_SIZE: usize = MAX_REALLOCATE_EXTENSIONS * <ExtensionType as CpiField>::MAX_SIZE;
+
+    #[inline(always)]
+    fn write_cpi_field(&self, bytes: &mut [MaybeUninit<u8>]) -> usize {
+        self.0.iter().fold(0, |len, extension_type| {
+            len + extension_type.write_cpi_field(&mut bytes[len..])
+        })
+    }
+}
+
+#[derive(CpiAccounts)]
+#[cpi(program = crate::ID, discriminator = [29], data(extension_types: ExtensionTypes<'_>))]
+pub struct Reallocate<'ix> {
+    /// Token account to grow
+    #[writable]
+    pub account: &'ix AccountView,
+    /// Account paying for the additional rent
+    #[writable]
+    #[signer]
+    pub payer: &'ix AccountView,
+    /// System program
+    pub system_program: &'ix AccountView,
+    /// Owner of the token account
+    #[signer(multisig)]
+    pub owner: &'ix AccountView,
+    /// Multisig signers when the owner is a `Multisig` account, empty otherwise
+    #[remaining(max = MAX_MULTISIG_SIGNERS)]
+    #[signer]
+    pub multisig_signers: &'ix [&'ix AccountView],
+}
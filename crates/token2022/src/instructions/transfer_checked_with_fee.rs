@@ -0,0 +1,29 @@
+// Copyright (c) 2026, Arcane Labs <dev@arcane.fi>
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::state::multisig::MAX_MULTISIG_SIGNERS;
+use hayabusa_common::{AccountView, Address};
+use hayabusa_cpi::{CheckProgramId, CpiCtx, CpiData, CpiField};
+use hayabusa_cpi_accounts_derive::CpiAccounts;
+use hayabusa_errors::{ProgramError, Result};
+use solana_instruction_view::{InstructionAccount, InstructionView};
+
+#[derive(CpiAccounts)]
+#[cpi(program = crate::ID, discriminator = [26, 1], data(amount: u64, decimals: u8, fee: u64))]
+pub struct TransferCheckedWithFee<'ix> {
+    /// Sender account
+    #[writable]
+    pub from: &'ix AccountView,
+    /// Mint account
+    pub mint: &'ix AccountView,
+    /// Recipient account
+    #[writable]
+    pub to: &'ix AccountView,
+    /// Authority account
+    #[signer(multisig)]
+    pub authority: &'ix AccountView,
+    /// Multisig signers when the authority is a `Multisig` account, empty otherwise
+    #[remaining(max = MAX_MULTISIG_SIGNERS)]
+    #[signer]
+    pub multisig_signers: &'ix [&'ix AccountView],
+}
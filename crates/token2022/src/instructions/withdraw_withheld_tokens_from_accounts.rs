@@ -0,0 +1,37 @@
+// Copyright (c) 2026, Arcane Labs <dev@arcane.fi>
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::state::multisig::MAX_MULTISIG_SIGNERS;
+use hayabusa_common::{AccountView, Address};
+use hayabusa_cpi::{CheckProgramId, CpiCtx, CpiData, CpiField};
+use hayabusa_cpi_accounts_derive::CpiAccounts;
+use hayabusa_errors::{ProgramError, Result};
+use solana_instruction_view::{InstructionAccount, InstructionView};
+
+/// Most token accounts withdrawn from by one `withdraw_withheld_tokens_from_accounts` call.
+pub const MAX_WITHDRAW_SOURCES: usize = 32;
+
+#[derive(CpiAccounts)]
+#[cpi(
+    program = crate::ID,
+    discriminator = [26, 3],
+    data(num_token_accounts: u8 = cpi_ctx.accounts.sources.len() as u8),
+)]
+pub struct WithdrawWithheldTokensFromAccounts<'ix> {
+    /// Mint account
+    pub mint: &'ix AccountView,
+    /// Token account receiving the withheld fees
+    #[writable]
+    pub destination: &'ix AccountView,
+    /// Mint's withdraw withheld authority
+    #[signer(multisig)]
+    pub authority: &'ix AccountView,
+    /// Multisig signers when the authority is a `Multisig` account, empty otherwise
+    #[remaining(max = MAX_MULTISIG_SIGNERS)]
+    #[signer]
+    pub multisig_signers: &'ix [&'ix AccountView],
+    /// Token accounts to withdraw the withheld fees of
+    #[remaining(max = MAX_WITHDRAW_SOURCES)]
+    #[writable]
+    pub sources: &'ix [&'ix AccountView],
+}
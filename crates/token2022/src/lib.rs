@@ -5,6 +5,10 @@
 
 pub mod instructions;
 pub mod state;
+mod optional_non_zero_address;
+
+pub use optional_non_zero_address::*;
+pub use hayabusa_token_interface::{UiAmount, MAX_UI_AMOUNT_LEN};
 
 hayabusa_common::declare_id!("TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb");
 
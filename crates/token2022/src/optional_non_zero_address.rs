@@ -0,0 +1,42 @@
+// Copyright (c) 2026, Arcane Labs <dev@arcane.fi>
+// SPDX-License-Identifier: Apache-2.0
+
+use core::mem::MaybeUninit;
+use hayabusa_common::Address;
+use hayabusa_cpi::CpiField;
+use hayabusa_utility::write_uninit_bytes;
+
+/// Optional address taken by the extension instructions.
+///
+/// Unlike `Option<&Address>`, always encoded as 32 bytes, the default address standing for `None`.
+#[derive(Clone, Copy, Default)]
+pub struct OptionalNonZeroAddress<'a>(pub Option<&'a Address>);
+
+impl<'a> From<Option<&'a Address>> for OptionalNonZeroAddress<'a> {
+    #[inline(always)]
+    fn from(address: Option<&'a Address>) -> Self {
+        Self(address)
+    }
+}
+
+impl<'a> From<&'a Address> for OptionalNonZeroAddress<'a> {
+    #[inline(always)]
+    fn from(address: &'a Address) -> Self {
+        Self(Some(address))
+    }
+}
+
+impl CpiField for OptionalNonZeroAddress<'_> {
+    const MAX_SIZE: usize = 32;
+
+    #[inline(always)]
+    fn write_cpi_field(&self, bytes: &mut [MaybeUninit<u8>]) -> usize {
+        match self.0 {
+            Some(address) => address.write_cpi_field(bytes),
+            None => {
+                write_uninit_bytes(bytes, &[0; 32]);
+                Self::MAX_SIZE
+            }
+        }
+    }
+}
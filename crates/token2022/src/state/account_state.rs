@@ -1,6 +1,9 @@
 // Copyright (c) 2026, Arcane Labs <dev@arcane.fi>
 // SPDX-License-Identifier: Apache-2.0
 
+use core::mem::MaybeUninit;
+use hayabusa_cpi::CpiField;
+
 #[repr(u8)]
 #[derive(Clone, Copy, Debug, PartialEq)]
 pub enum AccountState {
@@ -37,3 +40,12 @@ impl From<AccountState> for u8 {
         }
     }
 }
+
+impl CpiField for AccountState {
+    const MAX_SIZE: usize = 1;
+
+    #[inline(always)]
+    fn write_cpi_field(&self, bytes: &mut [MaybeUninit<u8>]) -> usize {
+        (*self as u8).write_cpi_field(bytes)
+    }
+}
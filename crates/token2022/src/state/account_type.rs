@@ -0,0 +1,16 @@
+// Copyright (c) 2026, Arcane Labs <dev@arcane.fi>
+// SPDX-License-Identifier: Apache-2.0
+
+/// Kind of an extended account, stored right after the base `TokenAccount` layout.
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum AccountType {
+    /// Marker for 0 data
+    Uninitialized,
+
+    /// Mint account with additional extensions
+    Mint,
+
+    /// Token holding account with additional extensions
+    Account,
+}
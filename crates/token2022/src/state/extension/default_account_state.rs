@@ -0,0 +1,34 @@
+// Copyright (c) 2026, Arcane Labs <dev@arcane.fi>
+// SPDX-License-Identifier: Apache-2.0
+
+use super::{Extension, ExtensionType};
+use crate::state::account_state::AccountState;
+use hayabusa_errors::{ProgramError, Result};
+use hayabusa_ser::FromBytesUnchecked;
+
+/// State new token accounts of a mint start in.
+#[repr(C)]
+pub struct DefaultAccountState {
+    /// Default state of new accounts.
+    state: u8,
+}
+
+impl FromBytesUnchecked for DefaultAccountState {}
+
+// SAFETY: `#[repr(C)]` over byte fields only
+unsafe impl Extension for DefaultAccountState {
+    const TYPE: ExtensionType = ExtensionType::DefaultAccountState;
+}
+
+impl DefaultAccountState {
+    /// Fails on a state byte this crate doesn't know.
+    #[inline(always)]
+    pub fn state(&self) -> Result<AccountState> {
+        match self.state {
+            0 => Ok(AccountState::Uninitialized),
+            1 => Ok(AccountState::Initialized),
+            2 => Ok(AccountState::Frozen),
+            _ => Err(ProgramError::InvalidAccountData),
+        }
+    }
+}
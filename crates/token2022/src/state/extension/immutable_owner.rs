@@ -0,0 +1,16 @@
+// Copyright (c) 2026, Arcane Labs <dev@arcane.fi>
+// SPDX-License-Identifier: Apache-2.0
+
+use super::{Extension, ExtensionType};
+use hayabusa_ser::FromBytesUnchecked;
+
+/// Marks a token account whose owner cannot be changed.
+#[repr(C)]
+pub struct ImmutableOwner;
+
+impl FromBytesUnchecked for ImmutableOwner {}
+
+// SAFETY: `#[repr(C)]` over byte fields only
+unsafe impl Extension for ImmutableOwner {
+    const TYPE: ExtensionType = ExtensionType::ImmutableOwner;
+}
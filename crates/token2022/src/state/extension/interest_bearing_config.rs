@@ -0,0 +1,55 @@
+// Copyright (c) 2026, Arcane Labs <dev@arcane.fi>
+// SPDX-License-Identifier: Apache-2.0
+
+use super::{optional_address, Extension, ExtensionType};
+use hayabusa_common::Address;
+use hayabusa_ser::FromBytesUnchecked;
+
+/// Interest rate of a mint, applied to UI amounts only.
+#[repr(C)]
+pub struct InterestBearingConfig {
+    /// Optional authority allowed to set the rate.
+    rate_authority: Address,
+
+    /// Timestamp of the mint initialization.
+    initialization_timestamp: [u8; 8],
+
+    /// Average rate, in basis points, from initialization to the last update.
+    pre_update_average_rate: [u8; 2],
+
+    /// Timestamp of the last rate update.
+    last_update_timestamp: [u8; 8],
+
+    /// Current rate, in basis points.
+    current_rate: [u8; 2],
+}
+
+impl FromBytesUnchecked for InterestBearingConfig {}
+
+// SAFETY: `#[repr(C)]` over byte fields only
+unsafe impl Extension for InterestBearingConfig {
+    const TYPE: ExtensionType = ExtensionType::InterestBearingConfig;
+}
+
+impl InterestBearingConfig {
+    #[inline(always)]
+    pub fn rate_authority(&self) -> Option<&Address> {
+        optional_address(&self.rate_authority)
+    }
+
+    pub fn initialization_timestamp(&self) -> i64 {
+        i64::from_le_bytes(self.initialization_timestamp)
+    }
+
+    pub fn pre_update_average_rate(&self) -> i16 {
+        i16::from_le_bytes(self.pre_update_average_rate)
+    }
+
+    pub fn last_update_timestamp(&self) -> i64 {
+        i64::from_le_bytes(self.last_update_timestamp)
+    }
+
+    pub fn current_rate(&self) -> i16 {
+        i16::from_le_bytes(self.current_rate)
+    }
+}
@@ -0,0 +1,26 @@
+// Copyright (c) 2026, Arcane Labs <dev@arcane.fi>
+// SPDX-License-Identifier: Apache-2.0
+
+use super::{Extension, ExtensionType};
+use hayabusa_ser::FromBytesUnchecked;
+
+/// Memo requirement of a token account.
+#[repr(C)]
+pub struct MemoTransfer {
+    /// Whether incoming transfers must be preceded by a memo.
+    require_incoming_transfer_memos: u8,
+}
+
+impl FromBytesUnchecked for MemoTransfer {}
+
+// SAFETY: `#[repr(C)]` over byte fields only
+unsafe impl Extension for MemoTransfer {
+    const TYPE: ExtensionType = ExtensionType::MemoTransfer;
+}
+
+impl MemoTransfer {
+    #[inline(always)]
+    pub fn require_incoming_transfer_memos(&self) -> bool {
+        self.require_incoming_transfer_memos == 1
+    }
+}
@@ -0,0 +1,35 @@
+// Copyright (c) 2026, Arcane Labs <dev@arcane.fi>
+// SPDX-License-Identifier: Apache-2.0
+
+use super::{optional_address, Extension, ExtensionType};
+use hayabusa_common::Address;
+use hayabusa_ser::FromBytesUnchecked;
+
+/// Location of the metadata of a mint.
+#[repr(C)]
+pub struct MetadataPointer {
+    /// Optional authority allowed to set the metadata address.
+    authority: Address,
+
+    /// Optional account holding the metadata, possibly the mint itself.
+    metadata_address: Address,
+}
+
+impl FromBytesUnchecked for MetadataPointer {}
+
+// SAFETY: `#[repr(C)]` over byte fields only
+unsafe impl Extension for MetadataPointer {
+    const TYPE: ExtensionType = ExtensionType::MetadataPointer;
+}
+
+impl MetadataPointer {
+    #[inline(always)]
+    pub fn authority(&self) -> Option<&Address> {
+        optional_address(&self.authority)
+    }
+
+    #[inline(always)]
+    pub fn metadata_address(&self) -> Option<&Address> {
+        optional_address(&self.metadata_address)
+    }
+}
@@ -0,0 +1,27 @@
+// Copyright (c) 2026, Arcane Labs <dev@arcane.fi>
+// SPDX-License-Identifier: Apache-2.0
+
+use super::{optional_address, Extension, ExtensionType};
+use hayabusa_common::Address;
+use hayabusa_ser::FromBytesUnchecked;
+
+/// Close authority of a mint.
+#[repr(C)]
+pub struct MintCloseAuthority {
+    /// Optional authority to close the mint.
+    close_authority: Address,
+}
+
+impl FromBytesUnchecked for MintCloseAuthority {}
+
+// SAFETY: `#[repr(C)]` over byte fields only
+unsafe impl Extension for MintCloseAuthority {
+    const TYPE: ExtensionType = ExtensionType::MintCloseAuthority;
+}
+
+impl MintCloseAuthority {
+    #[inline(always)]
+    pub fn close_authority(&self) -> Option<&Address> {
+        optional_address(&self.close_authority)
+    }
+}
@@ -0,0 +1,322 @@
+// Copyright (c) 2026, Arcane Labs <dev@arcane.fi>
+// SPDX-License-Identifier: Apache-2.0
+
+pub mod default_account_state;
+pub mod immutable_owner;
+pub mod interest_bearing_config;
+pub mod memo_transfer;
+pub mod metadata_pointer;
+pub mod mint_close_authority;
+pub mod non_transferable;
+pub mod permanent_delegate;
+pub mod transfer_fee_config;
+pub mod transfer_hook;
+
+pub use default_account_state::*;
+pub use immutable_owner::*;
+pub use interest_bearing_config::*;
+pub use memo_transfer::*;
+pub use metadata_pointer::*;
+pub use mint_close_authority::*;
+pub use non_transferable::*;
+pub use permanent_delegate::*;
+pub use transfer_fee_config::*;
+pub use transfer_hook::*;
+
+use super::{
+    account_type::AccountType, mint::Mint, multisig::Multisig, token_account::TokenAccount,
+};
+use core::mem::MaybeUninit;
+use hayabusa_common::Address;
+use hayabusa_cpi::CpiField;
+use hayabusa_errors::{ProgramError, Result};
+use hayabusa_ser::FromBytesUnchecked;
+
+/// Offset of the `AccountType` byte of an extended account.
+///
+/// Extended mints are zero-padded up to the `TokenAccount` length so both kinds share it.
+pub const ACCOUNT_TYPE_OFFSET: usize = TokenAccount::LEN;
+
+/// Offset of the first TLV entry of an extended account.
+pub const TLV_OFFSET: usize = ACCOUNT_TYPE_OFFSET + 1;
+
+/// Size of a TLV entry header, a `u16` type followed by a `u16` length.
+pub const TLV_HEADER_LEN: usize = 4;
+
+#[repr(u16)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ExtensionType {
+    /// Marks the end of the TLV entries, the rest of the account is padding
+    Uninitialized,
+    TransferFeeConfig,
+    TransferFeeAmount,
+    MintCloseAuthority,
+    ConfidentialTransferMint,
+    ConfidentialTransferAccount,
+    DefaultAccountState,
+    ImmutableOwner,
+    MemoTransfer,
+    NonTransferable,
+    InterestBearingConfig,
+    CpiGuard,
+    PermanentDelegate,
+    NonTransferableAccount,
+    TransferHook,
+    TransferHookAccount,
+    ConfidentialTransferFeeConfig,
+    ConfidentialTransferFeeAmount,
+    MetadataPointer,
+    TokenMetadata,
+    GroupPointer,
+    TokenGroup,
+    GroupMemberPointer,
+    TokenGroupMember,
+    ConfidentialMintBurn,
+    ScaledUiAmount,
+    Pausable,
+    PausableAccount,
+}
+
+impl ExtensionType {
+    /// Length of the extension value in a mint, `None` for account and variable-length extensions.
+    pub fn mint_value_len(self) -> Option<usize> {
+        match self {
+            Self::TransferFeeConfig => Some(TransferFeeConfig::LEN),
+            Self::MintCloseAuthority => Some(MintCloseAuthority::LEN),
+            Self::ConfidentialTransferMint => Some(65),
+            Self::DefaultAccountState => Some(DefaultAccountState::LEN),
+            Self::NonTransferable => Some(NonTransferable::LEN),
+            Self::InterestBearingConfig => Some(InterestBearingConfig::LEN),
+            Self::PermanentDelegate => Some(PermanentDelegate::LEN),
+            Self::TransferHook => Some(TransferHook::LEN),
+            Self::ConfidentialTransferFeeConfig => Some(129),
+            Self::MetadataPointer => Some(MetadataPointer::LEN),
+            Self::GroupPointer | Self::GroupMemberPointer => Some(64),
+            Self::TokenGroup => Some(80),
+            Self::TokenGroupMember => Some(72),
+            Self::ScaledUiAmount => Some(56),
+            Self::Pausable => Some(33),
+            _ => None,
+        }
+    }
+}
+
+impl CpiField for ExtensionType {
+    const MAX_SIZE: usize = 2;
+
+    #[inline(always)]
+    fn write_cpi_field(&self, bytes: &mut [MaybeUninit<u8>]) -> usize {
+        (*self as u16).write_cpi_field(bytes)
+    }
+}
+
+/// Zero-copy view of an extension value.
+///
+/// # Safety
+/// Implementors must be `#[repr(C)]` with an alignment of 1 and valid for any bit pattern, so
+/// they can be read in place from any TLV entry of the right length.
+pub unsafe trait Extension: FromBytesUnchecked {
+    const TYPE: ExtensionType;
+
+    /// The length of the extension value.
+    const LEN: usize = core::mem::size_of::<Self>();
+}
+
+/// Whether `data` holds an account of `account_type`, either with exactly `base_len` bytes or
+/// extended with TLV entries.
+#[inline(always)]
+pub fn is_account_type(data: &[u8], base_len: usize, account_type: AccountType) -> bool {
+    data.len() == base_len
+        || (data.len() > ACCOUNT_TYPE_OFFSET
+            && data.len() != Multisig::LEN
+            && data[ACCOUNT_TYPE_OFFSET] == account_type as u8)
+}
+
+/// One TLV entry of an extended account.
+#[derive(Clone, Copy)]
+pub struct TlvEntry<'a> {
+    /// Raw `ExtensionType`, kept as read so extensions unknown to this crate can be skipped
+    pub extension_type: u16,
+    pub value: &'a [u8],
+}
+
+impl<'a> TlvEntry<'a> {
+    /// The entry as `T`, if it holds that extension.
+    #[inline(always)]
+    pub fn get<T: Extension>(&self) -> Option<&'a T> {
+        if self.extension_type != T::TYPE as u16 || self.value.len() != T::LEN {
+            return None;
+        }
+
+        const { assert!(core::mem::align_of::<T>() == 1) };
+
+        // SAFETY: the length matches, and `Extension` implementors have an alignment of 1 and
+        // accept any bit pattern
+        Some(unsafe { T::from_bytes_unchecked(self.value) })
+    }
+}
+
+/// Iterator over the TLV entries of an extended account.
+///
+/// Stops at the first `Uninitialized` entry or at a truncated one.
+#[derive(Clone)]
+pub struct ExtensionIter<'a> {
+    tlv_data: &'a [u8],
+}
+
+impl<'a> Iterator for ExtensionIter<'a> {
+    type Item = TlvEntry<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let header = self.tlv_data.get(..TLV_HEADER_LEN)?;
+        let extension_type = u16::from_le_bytes([header[0], header[1]]);
+        let len = u16::from_le_bytes([header[2], header[3]]) as usize;
+
+        let value = match self.tlv_data.get(TLV_HEADER_LEN..TLV_HEADER_LEN + len) {
+            Some(value) if extension_type != ExtensionType::Uninitialized as u16 => value,
+            _ => {
+                self.tlv_data = &[];
+                return None;
+            }
+        };
+
+        self.tlv_data = &self.tlv_data[TLV_HEADER_LEN + len..];
+        Some(TlvEntry {
+            extension_type,
+            value,
+        })
+    }
+}
+
+/// The extensions of a mint or token account, empty when it has the base length.
+#[inline(always)]
+pub fn extensions(account_data: &[u8]) -> ExtensionIter<'_> {
+    ExtensionIter {
+        tlv_data: account_data.get(TLV_OFFSET..).unwrap_or(&[]),
+    }
+}
+
+/// Finds extension `T` in the data of a mint or token account.
+#[inline]
+pub fn get_extension<T: Extension>(account_data: &[u8]) -> Option<&T> {
+    extensions(account_data).find_map(|entry| entry.get::<T>())
+}
+
+/// Account size of a mint with `extension_types`, to allocate before initializing them.
+///
+/// Returns `ProgramError::InvalidArgument` for extensions without a fixed mint length, e.g.
+/// `TokenMetadata`, which is sized when written.
+pub fn mint_len(extension_types: &[ExtensionType]) -> Result<usize> {
+    if extension_types.is_empty() {
+        return Ok(Mint::LEN);
+    }
+
+    let mut len = TLV_OFFSET;
+    for extension_type in extension_types {
+        let value_len = extension_type
+            .mint_value_len()
+            .ok_or(ProgramError::InvalidArgument)?;
+        len += TLV_HEADER_LEN + value_len;
+    }
+
+    // an account of `Multisig::LEN` bytes is read as a multisig, so the program pads it
+    if len == Multisig::LEN {
+        len += core::mem::size_of::<ExtensionType>();
+    }
+
+    Ok(len)
+}
+
+/// An `OptionalNonZeroPubkey`, where the default address stands for `None`.
+#[inline(always)]
+fn optional_address(address: &Address) -> Option<&Address> {
+    if address.as_ref() == [0; 32] {
+        None
+    } else {
+        Some(address)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::mint::Mint;
+
+    fn push_entry(
+        data: &mut [u8],
+        offset: usize,
+        extension_type: ExtensionType,
+        value: &[u8],
+    ) -> usize {
+        data[offset..offset + 2].copy_from_slice(&(extension_type as u16).to_le_bytes());
+        data[offset + 2..offset + 4].copy_from_slice(&(value.len() as u16).to_le_bytes());
+        data[offset + 4..offset + 4 + value.len()].copy_from_slice(value);
+        offset + 4 + value.len()
+    }
+
+    #[test]
+    fn reads_mint_extensions() {
+        let close_authority = Address::new_from_array([3; 32]);
+
+        let mut data = [0u8; TLV_OFFSET + 64];
+        data[ACCOUNT_TYPE_OFFSET] = AccountType::Mint as u8;
+        let offset = push_entry(&mut data, TLV_OFFSET, ExtensionType::NonTransferable, &[]);
+        push_entry(
+            &mut data,
+            offset,
+            ExtensionType::MintCloseAuthority,
+            close_authority.as_ref(),
+        );
+
+        assert!(is_account_type(&data, Mint::LEN, AccountType::Mint));
+        assert!(!is_account_type(
+            &data,
+            TokenAccount::LEN,
+            AccountType::Account
+        ));
+        assert!(is_account_type(
+            &data[..Mint::LEN],
+            Mint::LEN,
+            AccountType::Mint
+        ));
+
+        // the zeroed tail ends the iteration
+        assert_eq!(extensions(&data).count(), 2);
+        assert!(get_extension::<NonTransferable>(&data).is_some());
+        assert!(get_extension::<PermanentDelegate>(&data).is_none());
+        assert_eq!(
+            get_extension::<MintCloseAuthority>(&data)
+                .unwrap()
+                .close_authority(),
+            Some(&close_authority),
+        );
+
+        // base mints have no extensions, truncated entries are not read
+        assert_eq!(extensions(&data[..Mint::LEN]).count(), 0);
+        assert_eq!(extensions(&data[..offset + 8]).count(), 1);
+    }
+
+    #[test]
+    fn computes_mint_len() {
+        assert_eq!(mint_len(&[]), Ok(Mint::LEN));
+        assert_eq!(
+            mint_len(&[ExtensionType::NonTransferable]),
+            Ok(TLV_OFFSET + 4)
+        );
+        assert_eq!(
+            mint_len(&[
+                ExtensionType::TransferFeeConfig,
+                ExtensionType::MintCloseAuthority
+            ]),
+            Ok(TLV_OFFSET + 4 + 108 + 4 + 32),
+        );
+        assert_eq!(
+            mint_len(&[ExtensionType::ImmutableOwner]),
+            Err(ProgramError::InvalidArgument),
+        );
+        assert_eq!(
+            mint_len(&[ExtensionType::TokenMetadata]),
+            Err(ProgramError::InvalidArgument),
+        );
+    }
+}
@@ -0,0 +1,16 @@
+// Copyright (c) 2026, Arcane Labs <dev@arcane.fi>
+// SPDX-License-Identifier: Apache-2.0
+
+use super::{Extension, ExtensionType};
+use hayabusa_ser::FromBytesUnchecked;
+
+/// Marks a mint whose tokens cannot be transferred.
+#[repr(C)]
+pub struct NonTransferable;
+
+impl FromBytesUnchecked for NonTransferable {}
+
+// SAFETY: `#[repr(C)]` over byte fields only
+unsafe impl Extension for NonTransferable {
+    const TYPE: ExtensionType = ExtensionType::NonTransferable;
+}
@@ -0,0 +1,27 @@
+// Copyright (c) 2026, Arcane Labs <dev@arcane.fi>
+// SPDX-License-Identifier: Apache-2.0
+
+use super::{optional_address, Extension, ExtensionType};
+use hayabusa_common::Address;
+use hayabusa_ser::FromBytesUnchecked;
+
+/// Delegate allowed to transfer or burn from every account of a mint.
+#[repr(C)]
+pub struct PermanentDelegate {
+    /// Optional permanent delegate.
+    delegate: Address,
+}
+
+impl FromBytesUnchecked for PermanentDelegate {}
+
+// SAFETY: `#[repr(C)]` over byte fields only
+unsafe impl Extension for PermanentDelegate {
+    const TYPE: ExtensionType = ExtensionType::PermanentDelegate;
+}
+
+impl PermanentDelegate {
+    #[inline(always)]
+    pub fn delegate(&self) -> Option<&Address> {
+        optional_address(&self.delegate)
+    }
+}
@@ -0,0 +1,106 @@
+// Copyright (c) 2026, Arcane Labs <dev@arcane.fi>
+// SPDX-License-Identifier: Apache-2.0
+
+use super::{optional_address, Extension, ExtensionType};
+use hayabusa_common::Address;
+use hayabusa_ser::FromBytesUnchecked;
+
+/// Maximum transfer fee in basis points.
+pub const MAX_FEE_BASIS_POINTS: u16 = 10_000;
+
+/// Transfer fee schedule, effective from an epoch.
+#[repr(C)]
+pub struct TransferFee {
+    /// First epoch the fee applies to.
+    epoch: [u8; 8],
+
+    /// Maximum fee charged per transfer, in base units.
+    maximum_fee: [u8; 8],
+
+    /// Fee charged per transfer, in basis points of the amount.
+    transfer_fee_basis_points: [u8; 2],
+}
+
+impl TransferFee {
+    pub fn epoch(&self) -> u64 {
+        u64::from_le_bytes(self.epoch)
+    }
+
+    pub fn maximum_fee(&self) -> u64 {
+        u64::from_le_bytes(self.maximum_fee)
+    }
+
+    pub fn transfer_fee_basis_points(&self) -> u16 {
+        u16::from_le_bytes(self.transfer_fee_basis_points)
+    }
+
+    /// Fee withheld when transferring `amount`, rounded up and capped at the maximum fee.
+    pub fn calculate_fee(&self, amount: u64) -> u64 {
+        let basis_points = self.transfer_fee_basis_points() as u128;
+        if basis_points == 0 || amount == 0 {
+            return 0;
+        }
+
+        let fee = (amount as u128 * basis_points).div_ceil(MAX_FEE_BASIS_POINTS as u128);
+        fee.min(self.maximum_fee() as u128) as u64
+    }
+}
+
+/// Transfer fee configuration of a mint.
+#[repr(C)]
+pub struct TransferFeeConfig {
+    /// Optional authority allowed to set the fee.
+    transfer_fee_config_authority: Address,
+
+    /// Optional authority allowed to withdraw withheld fees.
+    withdraw_withheld_authority: Address,
+
+    /// Fees withheld on the mint itself.
+    withheld_amount: [u8; 8],
+
+    /// Fee applying before `newer_transfer_fee`'s epoch.
+    older_transfer_fee: TransferFee,
+
+    /// Fee applying from its epoch onwards.
+    newer_transfer_fee: TransferFee,
+}
+
+impl FromBytesUnchecked for TransferFeeConfig {}
+
+// SAFETY: `#[repr(C)]` over byte fields only
+unsafe impl Extension for TransferFeeConfig {
+    const TYPE: ExtensionType = ExtensionType::TransferFeeConfig;
+}
+
+impl TransferFeeConfig {
+    #[inline(always)]
+    pub fn transfer_fee_config_authority(&self) -> Option<&Address> {
+        optional_address(&self.transfer_fee_config_authority)
+    }
+
+    #[inline(always)]
+    pub fn withdraw_withheld_authority(&self) -> Option<&Address> {
+        optional_address(&self.withdraw_withheld_authority)
+    }
+
+    pub fn withheld_amount(&self) -> u64 {
+        u64::from_le_bytes(self.withheld_amount)
+    }
+
+    pub fn older_transfer_fee(&self) -> &TransferFee {
+        &self.older_transfer_fee
+    }
+
+    pub fn newer_transfer_fee(&self) -> &TransferFee {
+        &self.newer_transfer_fee
+    }
+
+    /// The fee in effect at `epoch`.
+    pub fn epoch_fee(&self, epoch: u64) -> &TransferFee {
+        if epoch >= self.newer_transfer_fee.epoch() {
+            &self.newer_transfer_fee
+        } else {
+            &self.older_transfer_fee
+        }
+    }
+}
@@ -0,0 +1,35 @@
+// Copyright (c) 2026, Arcane Labs <dev@arcane.fi>
+// SPDX-License-Identifier: Apache-2.0
+
+use super::{optional_address, Extension, ExtensionType};
+use hayabusa_common::Address;
+use hayabusa_ser::FromBytesUnchecked;
+
+/// Program invoked on every transfer of a mint.
+#[repr(C)]
+pub struct TransferHook {
+    /// Optional authority allowed to set the program.
+    authority: Address,
+
+    /// Optional transfer hook program.
+    program_id: Address,
+}
+
+impl FromBytesUnchecked for TransferHook {}
+
+// SAFETY: `#[repr(C)]` over byte fields only
+unsafe impl Extension for TransferHook {
+    const TYPE: ExtensionType = ExtensionType::TransferHook;
+}
+
+impl TransferHook {
+    #[inline(always)]
+    pub fn authority(&self) -> Option<&Address> {
+        optional_address(&self.authority)
+    }
+
+    #[inline(always)]
+    pub fn program_id(&self) -> Option<&Address> {
+        optional_address(&self.program_id)
+    }
+}
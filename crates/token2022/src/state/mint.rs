@@ -1,6 +1,7 @@
 // Copyright (c) 2026, Arcane Labs <dev@arcane.fi>
 // SPDX-License-Identifier: Apache-2.0
 
+use super::{account_type::AccountType, extension::is_account_type};
 use hayabusa_common::{AccountView, Address, Ref};
 use hayabusa_errors::{ProgramError, Result};
 use hayabusa_ser::{
@@ -9,6 +10,8 @@ use hayabusa_ser::{
 use hayabusa_utility::{error_msg, hint::unlikely, OwnerProgram};
 
 /// Mint data.
+///
+/// Accounts with extensions are accepted, read them with [`get_extension`](super::extension::get_extension).
 #[repr(C)]
 pub struct Mint {
     /// Indicates whether the mint authority is present or not.
@@ -44,11 +47,11 @@ impl Zc for Mint {}
 impl Deserialize for Mint {}
 
 /// SAFETY:
-/// Account data length is validated, and the Mint struct is properly aligned
+/// Account data holds at least a `Mint`, and the Mint struct is properly aligned
 /// so it is safe to cast from raw ptr.
 unsafe impl RawZcDeserialize for Mint {
-    fn try_deserialize_raw(account_view: &AccountView) -> Result<Ref<Self>> {
-        if unlikely(account_view.data_len() != Self::LEN) {
+    fn try_deserialize_raw(account_view: &AccountView) -> Result<Ref<'_, Self>> {
+        if unlikely(account_view.data_len() < Self::LEN) {
             error_msg!(
                 "Mint::try_deserialize_raw: data length mismatch",
                 ProgramError::InvalidAccountData,
@@ -62,16 +65,23 @@ unsafe impl RawZcDeserialize for Mint {
             );
         }
 
-        Ok(Ref::map(account_view.try_borrow()?, |d| unsafe {
-            Self::from_bytes_unchecked(d)
-        }))
+        let data = account_view.try_borrow()?;
+
+        if unlikely(!is_account_type(&data, Self::LEN, AccountType::Mint)) {
+            error_msg!(
+                "Mint::try_deserialize_raw: invalid account type",
+                ProgramError::InvalidAccountData,
+            );
+        }
+
+        Ok(Ref::map(data, |d| unsafe { Self::from_bytes_unchecked(d) }))
     }
 }
 
 impl RawZcDeserializeUnchecked for Mint {
     #[inline(always)]
     unsafe fn try_deserialize_raw_unchecked(account_view: &AccountView) -> Result<&Self> {
-        if unlikely(account_view.data_len() != Self::LEN) {
+        if unlikely(account_view.data_len() < Self::LEN) {
             error_msg!(
                 "Mint::try_deserialize_raw_unchecked: data length mismatch",
                 ProgramError::InvalidAccountData,
@@ -85,7 +95,16 @@ impl RawZcDeserializeUnchecked for Mint {
             );
         }
 
-        Ok(Self::from_bytes_unchecked(account_view.borrow_unchecked()))
+        let data = account_view.borrow_unchecked();
+
+        if unlikely(!is_account_type(data, Self::LEN, AccountType::Mint)) {
+            error_msg!(
+                "Mint::try_deserialize_raw_unchecked: invalid account type",
+                ProgramError::InvalidAccountData,
+            );
+        }
+
+        Ok(Self::from_bytes_unchecked(data))
     }
 }
 
@@ -2,6 +2,8 @@
 // SPDX-License-Identifier: Apache-2.0
 
 pub mod account_state;
+pub mod account_type;
+pub mod extension;
 pub mod mint;
 pub mod multisig;
 pub mod token_account;
@@ -1,7 +1,7 @@
 // Copyright (c) 2026, Arcane Labs <dev@arcane.fi>
 // SPDX-License-Identifier: Apache-2.0
 
-use super::AccountState;
+use super::{account_type::AccountType, extension::is_account_type, AccountState};
 use hayabusa_common::{AccountView, Address, Ref};
 use hayabusa_errors::{ProgramError, Result};
 use hayabusa_ser::{
@@ -10,6 +10,8 @@ use hayabusa_ser::{
 use hayabusa_utility::{error_msg, hint::unlikely};
 
 /// Token account data.
+///
+/// Accounts with extensions are accepted, read them with [`get_extension`](super::extension::get_extension).
 #[repr(C)]
 pub struct TokenAccount {
     /// The mint associated with this account
@@ -56,8 +58,8 @@ impl Deserialize for TokenAccount {}
 
 unsafe impl RawZcDeserialize for TokenAccount {
     #[inline]
-    fn try_deserialize_raw(account_view: &AccountView) -> Result<Ref<Self>> {
-        if unlikely(account_view.data_len() != Self::LEN) {
+    fn try_deserialize_raw(account_view: &AccountView) -> Result<Ref<'_, Self>> {
+        if unlikely(account_view.data_len() < Self::LEN) {
             error_msg!(
                 "TokenAccount::try_deserialize_raw: data length mismatch",
                 ProgramError::InvalidAccountData,
@@ -71,16 +73,23 @@ unsafe impl RawZcDeserialize for TokenAccount {
             );
         }
 
-        Ok(Ref::map(account_view.try_borrow()?, |d| unsafe {
-            Self::from_bytes_unchecked(d)
-        }))
+        let data = account_view.try_borrow()?;
+
+        if unlikely(!is_account_type(&data, Self::LEN, AccountType::Account)) {
+            error_msg!(
+                "TokenAccount::try_deserialize_raw: invalid account type",
+                ProgramError::InvalidAccountData,
+            );
+        }
+
+        Ok(Ref::map(data, |d| unsafe { Self::from_bytes_unchecked(d) }))
     }
 }
 
 impl RawZcDeserializeUnchecked for TokenAccount {
     #[inline(always)]
     unsafe fn try_deserialize_raw_unchecked(account_view: &AccountView) -> Result<&Self> {
-        if unlikely(account_view.data_len() != Self::LEN) {
+        if unlikely(account_view.data_len() < Self::LEN) {
             error_msg!(
                 "TokenAccount::try_deserialize_raw_unchecked: data length mismatch",
                 ProgramError::InvalidAccountData,
@@ -94,7 +103,16 @@ impl RawZcDeserializeUnchecked for TokenAccount {
             );
         }
 
-        Ok(Self::from_bytes_unchecked(account_view.borrow_unchecked()))
+        let data = account_view.borrow_unchecked();
+
+        if unlikely(!is_account_type(data, Self::LEN, AccountType::Account)) {
+            error_msg!(
+                "TokenAccount::try_deserialize_raw_unchecked: invalid account type",
+                ProgramError::InvalidAccountData,
+            );
+        }
+
+        Ok(Self::from_bytes_unchecked(data))
     }
 }
 
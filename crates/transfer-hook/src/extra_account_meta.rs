@@ -0,0 +1,196 @@
+// Copyright (c) 2026, Arcane Labs <dev@arcane.fi>
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::Seed;
+use bytemuck::{Pod, Zeroable};
+use hayabusa_common::{AccountView, Address};
+use hayabusa_errors::{ErrorCode, ProgramError, Result};
+use hayabusa_pda::find_program_address;
+use hayabusa_syscalls::{MAX_SEEDS, MAX_SEED_LEN, MAX_TOTAL_LEN};
+
+/// Set on the discriminator of a PDA derived by another program, the low bits holding the
+/// index of that program's account.
+const EXTERNAL_PDA_FLAG: u8 = 1 << 7;
+
+/// Number of accounts of the `Execute` instruction before the extra accounts.
+pub const EXECUTE_ACCOUNTS_LEN: usize = 5;
+
+/// Accounts of an `Execute` instruction, indexed the way seeds reference them.
+pub struct ExecuteAccounts<'a> {
+    /// Source token account, mint, destination token account, owner and validation account
+    pub accounts: [&'a AccountView; EXECUTE_ACCOUNTS_LEN],
+    /// Extra accounts, in the order of the list
+    pub extra_accounts: &'a [AccountView],
+}
+
+impl<'a> ExecuteAccounts<'a> {
+    /// The account at `index` of the instruction.
+    #[inline(always)]
+    pub fn get(&self, index: usize) -> Option<&'a AccountView> {
+        match index.checked_sub(EXECUTE_ACCOUNTS_LEN) {
+            None => Some(self.accounts[index]),
+            Some(index) => self.extra_accounts.get(index),
+        }
+    }
+}
+
+/// Extra account required by the transfer hook, as stored in the `ExtraAccountMetaList`.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ExtraAccountMeta {
+    /// Kind of address: fixed, PDA of the hook program, or PDA of another program
+    discriminator: u8,
+
+    /// The address, or the packed seeds of the PDA.
+    address_config: [u8; 32],
+
+    /// Whether the account must sign.
+    is_signer: u8,
+
+    /// Whether the account must be writable.
+    is_writable: u8,
+}
+
+// SAFETY: only byte fields, so no padding and any bit pattern is valid
+unsafe impl Zeroable for ExtraAccountMeta {}
+unsafe impl Pod for ExtraAccountMeta {}
+
+impl ExtraAccountMeta {
+    pub const LEN: usize = core::mem::size_of::<ExtraAccountMeta>();
+
+    /// Account at a fixed address.
+    #[inline(always)]
+    pub fn new_with_address(address: &Address, is_signer: bool, is_writable: bool) -> Self {
+        Self {
+            discriminator: 0,
+            address_config: address.to_bytes(),
+            is_signer: is_signer as u8,
+            is_writable: is_writable as u8,
+        }
+    }
+
+    /// PDA of the transfer hook program.
+    #[inline(always)]
+    pub fn new_with_seeds(seeds: &[Seed<'_>], is_signer: bool, is_writable: bool) -> Result<Self> {
+        Ok(Self {
+            discriminator: 1,
+            address_config: Seed::pack_address_config(seeds)?,
+            is_signer: is_signer as u8,
+            is_writable: is_writable as u8,
+        })
+    }
+
+    /// PDA of the program at `program_index` of the `Execute` accounts.
+    ///
+    /// Returns `ProgramError::InvalidArgument` if `program_index` doesn't fit in 7 bits.
+    #[inline(always)]
+    pub fn new_external_pda_with_seeds(
+        program_index: u8,
+        seeds: &[Seed<'_>],
+        is_signer: bool,
+        is_writable: bool,
+    ) -> Result<Self> {
+        if program_index >= EXTERNAL_PDA_FLAG {
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        Ok(Self {
+            discriminator: EXTERNAL_PDA_FLAG | program_index,
+            address_config: Seed::pack_address_config(seeds)?,
+            is_signer: is_signer as u8,
+            is_writable: is_writable as u8,
+        })
+    }
+
+    #[inline(always)]
+    pub fn is_signer(&self) -> bool {
+        self.is_signer == 1
+    }
+
+    #[inline(always)]
+    pub fn is_writable(&self) -> bool {
+        self.is_writable == 1
+    }
+
+    /// Computes the address of the account for an `Execute` instruction of `program_id`.
+    pub fn resolve(
+        &self,
+        instruction_data: &[u8],
+        program_id: &Address,
+        accounts: &ExecuteAccounts<'_>,
+    ) -> Result<Address> {
+        let program_id = match self.discriminator {
+            0 => return Ok(Address::new_from_array(self.address_config)),
+            1 => program_id,
+            discriminator if discriminator & EXTERNAL_PDA_FLAG != 0 => accounts
+                .get((discriminator & !EXTERNAL_PDA_FLAG) as usize)
+                .ok_or(ProgramError::NotEnoughAccountKeys)?
+                .address(),
+            _ => return Err(ProgramError::InvalidAccountData),
+        };
+
+        // seeds are copied out so account data isn't borrowed across the derivation
+        let mut buf = [0u8; MAX_TOTAL_LEN];
+        let mut ends = [0usize; MAX_SEEDS];
+        let mut count = 0;
+        let mut len = 0;
+
+        for seed in Seed::unpack_address_config(&self.address_config) {
+            let end = match seed? {
+                Seed::Literal(bytes) => write_seed(&mut buf, len, bytes)?,
+                Seed::InstructionData { index, length } => {
+                    let bytes = instruction_data
+                        .get(index as usize..index as usize + length as usize)
+                        .ok_or(ProgramError::InvalidInstructionData)?;
+                    write_seed(&mut buf, len, bytes)?
+                }
+                Seed::AccountKey { index } => {
+                    let account = accounts
+                        .get(index as usize)
+                        .ok_or(ProgramError::NotEnoughAccountKeys)?;
+                    write_seed(&mut buf, len, account.address().as_ref())?
+                }
+                Seed::AccountData {
+                    account_index,
+                    data_index,
+                    length,
+                } => {
+                    let account = accounts
+                        .get(account_index as usize)
+                        .ok_or(ProgramError::NotEnoughAccountKeys)?;
+                    let data = account.try_borrow()?;
+                    let bytes = data
+                        .get(data_index as usize..data_index as usize + length as usize)
+                        .ok_or(ProgramError::InvalidAccountData)?;
+                    write_seed(&mut buf, len, bytes)?
+                }
+            };
+
+            // an address config holds at most 16 seeds of 2 bytes
+            ends[count] = end;
+            count += 1;
+            len = end;
+        }
+
+        let mut seeds: [&[u8]; MAX_SEEDS] = [&[]; MAX_SEEDS];
+        let mut start = 0;
+        for (seed, &end) in seeds.iter_mut().zip(&ends[..count]) {
+            *seed = &buf[start..end];
+            start = end;
+        }
+
+        find_program_address(&seeds[..count], program_id).map(|(address, _)| address)
+    }
+}
+
+/// Appends `bytes` at `offset` of `buf`, returning the offset of its end.
+#[inline(always)]
+fn write_seed(buf: &mut [u8; MAX_TOTAL_LEN], offset: usize, bytes: &[u8]) -> Result<usize> {
+    if bytes.len() > MAX_SEED_LEN {
+        return Err(ErrorCode::SeedsTooLong.into());
+    }
+
+    let end = offset + bytes.len();
+    buf[offset..end].copy_from_slice(bytes);
+    Ok(end)
+}
@@ -0,0 +1,232 @@
+// Copyright (c) 2026, Arcane Labs <dev@arcane.fi>
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{ExecuteAccounts, ExtraAccountMeta, EXECUTE_DISCRIMINATOR};
+use hayabusa_common::{address_eq, AccountView, Address, Ref};
+use hayabusa_errors::{ErrorCode, ProgramError, Result};
+use hayabusa_utility::{error_msg, hint::unlikely};
+
+/// Layout of the validation account: a TLV entry tagged `EXECUTE_DISCRIMINATOR` holding a
+/// `u32` length, a `u32` count and the `ExtraAccountMeta`s.
+pub struct ExtraAccountMetaList;
+
+impl ExtraAccountMetaList {
+    /// Length of the TLV header and of the count.
+    pub const HEADER_LEN: usize = 8 + 4 + 4;
+
+    /// Account size needed for `num_metas` extra accounts.
+    #[inline(always)]
+    pub const fn size_of(num_metas: usize) -> usize {
+        Self::HEADER_LEN + num_metas * ExtraAccountMeta::LEN
+    }
+
+    /// The extra accounts stored in `data`.
+    pub fn unpack(data: &[u8]) -> Result<&[ExtraAccountMeta]> {
+        if unlikely(data.len() < Self::HEADER_LEN || data[..8] != EXECUTE_DISCRIMINATOR) {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        let len = u32::from_le_bytes(data[8..12].try_into().unwrap()) as usize;
+        let count = u32::from_le_bytes(data[12..16].try_into().unwrap()) as usize;
+
+        if unlikely(len != 4 + count * ExtraAccountMeta::LEN) {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        data.get(Self::HEADER_LEN..Self::size_of(count))
+            .and_then(|metas| bytemuck::try_cast_slice(metas).ok())
+            .ok_or(ProgramError::InvalidAccountData)
+    }
+
+    /// Writes `metas` to the zeroed `data` of a new validation account.
+    pub fn init(data: &mut [u8], metas: &[ExtraAccountMeta]) -> Result<()> {
+        if unlikely(data.iter().take(8).any(|&byte| byte != 0)) {
+            return Err(ProgramError::AccountAlreadyInitialized);
+        }
+
+        Self::write(data, metas)
+    }
+
+    /// Replaces the extra accounts of an initialized validation account, which must already be
+    /// resized to `size_of(metas.len())`.
+    pub fn update(data: &mut [u8], metas: &[ExtraAccountMeta]) -> Result<()> {
+        Self::unpack(data)?;
+
+        Self::write(data, metas)
+    }
+
+    /// Borrows the extra accounts of a validation account.
+    #[inline]
+    pub fn try_deserialize(account_view: &AccountView) -> Result<Ref<'_, [ExtraAccountMeta]>> {
+        Ref::try_map(account_view.try_borrow()?, Self::unpack).map_err(|(_, err)| err)
+    }
+
+    /// Initializes an allocated validation account with `metas`.
+    #[inline]
+    pub fn init_account(account_view: &AccountView, metas: &[ExtraAccountMeta]) -> Result<()> {
+        Self::init(&mut account_view.try_borrow_mut()?, metas)
+    }
+
+    /// Resizes a validation account to fit `metas` and writes them.
+    ///
+    /// The caller tops up the rent of a growing account.
+    #[inline]
+    pub fn update_account(account_view: &AccountView, metas: &[ExtraAccountMeta]) -> Result<()> {
+        Self::unpack(&account_view.try_borrow()?)?;
+        account_view.resize(Self::size_of(metas.len()))?;
+
+        // the header still holds the old count, which a shrunk account no longer fits
+        Self::write(&mut account_view.try_borrow_mut()?, metas)
+    }
+
+    /// Checks the extra accounts passed to `Execute` are the ones `metas` resolve to, with at
+    /// least the required signer and writable flags.
+    pub fn check_account_views(
+        metas: &[ExtraAccountMeta],
+        instruction_data: &[u8],
+        program_id: &Address,
+        accounts: &ExecuteAccounts<'_>,
+    ) -> Result<()> {
+        if unlikely(accounts.extra_accounts.len() < metas.len()) {
+            error_msg!(
+                "ExtraAccountMetaList::check_account_views: missing extra accounts",
+                ProgramError::NotEnoughAccountKeys,
+            );
+        }
+
+        for (meta, account_view) in metas.iter().zip(accounts.extra_accounts) {
+            let address = meta.resolve(instruction_data, program_id, accounts)?;
+
+            if unlikely(!address_eq(account_view.address(), &address)) {
+                error_msg!(
+                    "ExtraAccountMetaList::check_account_views: invalid extra account",
+                    ErrorCode::InvalidAccount,
+                );
+            }
+
+            if unlikely(meta.is_signer() && !account_view.is_signer()) {
+                error_msg!(
+                    "ExtraAccountMetaList::check_account_views: extra account must sign",
+                    ErrorCode::AccountNotSigner,
+                );
+            }
+
+            if unlikely(meta.is_writable() && !account_view.is_writable()) {
+                error_msg!(
+                    "ExtraAccountMetaList::check_account_views: extra account must be writable",
+                    ErrorCode::AccountNotWritable,
+                );
+            }
+        }
+
+        Ok(())
+    }
+
+    #[inline]
+    fn write(data: &mut [u8], metas: &[ExtraAccountMeta]) -> Result<()> {
+        let size = Self::size_of(metas.len());
+        if unlikely(data.len() < size) {
+            return Err(ProgramError::AccountDataTooSmall);
+        }
+
+        data[..8].copy_from_slice(&EXECUTE_DISCRIMINATOR);
+        data[8..12]
+            .copy_from_slice(&((4 + metas.len() * ExtraAccountMeta::LEN) as u32).to_le_bytes());
+        data[12..16].copy_from_slice(&(metas.len() as u32).to_le_bytes());
+        data[Self::HEADER_LEN..size].copy_from_slice(bytemuck::cast_slice(metas));
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Seed;
+    use hayabusa_common::account_view::{RuntimeAccount, NOT_BORROWED};
+
+    #[repr(C)]
+    struct TestAccount {
+        header: RuntimeAccount,
+        data: [u8; ExtraAccountMetaList::size_of(2)],
+    }
+
+    #[test]
+    fn writes_and_reads_metas() {
+        let wallet = Address::new_from_array([9; 32]);
+        let seeds = [
+            Seed::Literal(b"counter"),
+            Seed::AccountKey { index: 1 },
+            Seed::InstructionData {
+                index: 8,
+                length: 8,
+            },
+            Seed::AccountData {
+                account_index: 0,
+                data_index: 32,
+                length: 32,
+            },
+        ];
+
+        let metas = [
+            ExtraAccountMeta::new_with_address(&wallet, false, true),
+            ExtraAccountMeta::new_with_seeds(&seeds, false, false).unwrap(),
+        ];
+
+        let mut data = [0u8; ExtraAccountMetaList::size_of(2)];
+        ExtraAccountMetaList::init(&mut data, &metas).unwrap();
+        assert_eq!(ExtraAccountMetaList::unpack(&data), Ok(&metas[..]));
+        assert_eq!(
+            ExtraAccountMetaList::init(&mut data, &metas),
+            Err(ProgramError::AccountAlreadyInitialized),
+        );
+
+        // the packed seeds read back in order
+        let unpacked = ExtraAccountMetaList::unpack(&data).unwrap()[1];
+        let mut config = [0; 32];
+        config.copy_from_slice(&bytemuck::bytes_of(&unpacked)[1..33]);
+        let mut read = Seed::unpack_address_config(&config);
+        for seed in seeds {
+            assert_eq!(read.next(), Some(Ok(seed)));
+        }
+        assert_eq!(read.next(), None);
+
+        // a shorter list leaves the trailing bytes unread
+        ExtraAccountMetaList::update(&mut data, &metas[..1]).unwrap();
+        assert_eq!(ExtraAccountMetaList::unpack(&data), Ok(&metas[..1]));
+
+        assert_eq!(
+            Seed::pack_address_config(&[Seed::Literal(&[0; 31])]),
+            Err(ErrorCode::InvalidSeeds.into()),
+        );
+    }
+
+    #[test]
+    fn update_account_shrinks() {
+        let metas = [
+            ExtraAccountMeta::new_with_address(&Address::new_from_array([1; 32]), false, false),
+            ExtraAccountMeta::new_with_address(&Address::new_from_array([2; 32]), true, false),
+        ];
+
+        let mut account = TestAccount {
+            header: RuntimeAccount {
+                borrow_state: NOT_BORROWED,
+                is_writable: 1,
+                data_len: ExtraAccountMetaList::size_of(2) as u64,
+                ..Default::default()
+            },
+            data: [0; ExtraAccountMetaList::size_of(2)],
+        };
+        // SAFETY: the account header is followed by `data_len` bytes of data
+        let account_view = unsafe { AccountView::new_unchecked(&mut account.header) };
+
+        ExtraAccountMetaList::init_account(&account_view, &metas).unwrap();
+        ExtraAccountMetaList::update_account(&account_view, &metas[1..]).unwrap();
+
+        assert_eq!(account_view.data_len(), ExtraAccountMetaList::size_of(1));
+        assert_eq!(
+            ExtraAccountMetaList::try_deserialize(&account_view).as_deref(),
+            Ok(&metas[1..]),
+        );
+    }
+}
@@ -0,0 +1,56 @@
+// Copyright (c) 2026, Arcane Labs <dev@arcane.fi>
+// SPDX-License-Identifier: Apache-2.0
+
+#![no_std]
+
+mod extra_account_meta;
+mod extra_account_meta_list;
+mod seed;
+
+pub use extra_account_meta::*;
+pub use extra_account_meta_list::*;
+pub use seed::*;
+
+use hayabusa_common::Address;
+use hayabusa_errors::Result;
+use hayabusa_pda::find_program_address;
+
+/// Discriminator of the `Execute` instruction Token-2022 invokes on every transfer, the first
+/// 8 bytes of `sha256("spl-transfer-hook-interface:execute")`.
+///
+/// Also tags the `ExtraAccountMetaList` TLV entry of the validation account.
+pub const EXECUTE_DISCRIMINATOR: [u8; 8] = [105, 37, 101, 197, 75, 251, 102, 26];
+
+/// Seed of the validation account, followed by the mint.
+pub const EXTRA_ACCOUNT_METAS_SEED: &[u8] = b"extra-account-metas";
+
+/// Derives the validation account of `mint` for the transfer hook `program_id`, returning its
+/// address and bump.
+#[inline]
+pub fn find_extra_account_metas_address(
+    mint: &Address,
+    program_id: &Address,
+) -> Result<(Address, u8)> {
+    find_program_address(&[EXTRA_ACCOUNT_METAS_SEED, mint.as_ref()], program_id)
+}
+
+/// `Execute` instruction data for `amount`, as seeds of type `InstructionData` index it.
+#[inline(always)]
+pub fn execute_instruction_data(amount: u64) -> [u8; 16] {
+    let mut data = [0; 16];
+    data[..8].copy_from_slice(&EXECUTE_DISCRIMINATOR);
+    data[8..].copy_from_slice(&amount.to_le_bytes());
+    data
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sha2::{Digest, Sha256};
+
+    #[test]
+    fn execute_discriminator_matches_interface_hash() {
+        let hash = Sha256::digest(b"spl-transfer-hook-interface:execute");
+        assert_eq!(EXECUTE_DISCRIMINATOR, hash[..8]);
+    }
+}
@@ -0,0 +1,114 @@
+// Copyright (c) 2026, Arcane Labs <dev@arcane.fi>
+// SPDX-License-Identifier: Apache-2.0
+
+use hayabusa_errors::{ErrorCode, ProgramError, Result};
+
+/// Seed of a PDA resolved when the hook executes, packed into the address config of an
+/// `ExtraAccountMeta`.
+///
+/// Indexes refer to the `Execute` instruction: its data (the discriminator included) and its
+/// accounts (source, mint, destination, owner, validation account, then the extra accounts).
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Seed<'a> {
+    /// Bytes fixed when the list is written
+    Literal(&'a [u8]),
+    /// `length` bytes of the instruction data, starting at `index`
+    InstructionData { index: u8, length: u8 },
+    /// Address of the account at `index`
+    AccountKey { index: u8 },
+    /// `length` bytes of the data of the account at `account_index`, starting at `data_index`
+    AccountData {
+        account_index: u8,
+        data_index: u8,
+        length: u8,
+    },
+}
+
+impl<'a> Seed<'a> {
+    /// Packs `seeds` into an address config.
+    ///
+    /// Returns `ErrorCode::InvalidSeeds` if they don't fit in its 32 bytes.
+    pub fn pack_address_config(seeds: &[Seed<'_>]) -> Result<[u8; 32]> {
+        let mut config = [0; 32];
+        let mut offset = 0;
+
+        for seed in seeds {
+            let len = seed.packed_len();
+            let Some(bytes) = config.get_mut(offset..offset + len) else {
+                return Err(ErrorCode::InvalidSeeds.into());
+            };
+
+            match *seed {
+                Seed::Literal(literal) => {
+                    bytes[0] = 1;
+                    bytes[1] = literal.len() as u8;
+                    bytes[2..].copy_from_slice(literal);
+                }
+                Seed::InstructionData { index, length } => {
+                    bytes.copy_from_slice(&[2, index, length])
+                }
+                Seed::AccountKey { index } => bytes.copy_from_slice(&[3, index]),
+                Seed::AccountData {
+                    account_index,
+                    data_index,
+                    length,
+                } => bytes.copy_from_slice(&[4, account_index, data_index, length]),
+            }
+
+            offset += len;
+        }
+
+        Ok(config)
+    }
+
+    /// The seeds packed in `config`.
+    #[inline(always)]
+    pub fn unpack_address_config(config: &'a [u8; 32]) -> SeedIter<'a> {
+        SeedIter { config }
+    }
+
+    #[inline(always)]
+    fn packed_len(&self) -> usize {
+        match self {
+            Seed::Literal(literal) => 2 + literal.len(),
+            Seed::InstructionData { .. } => 3,
+            Seed::AccountKey { .. } => 2,
+            Seed::AccountData { .. } => 4,
+        }
+    }
+}
+
+/// Iterator over the seeds of an address config, ending at the first zero byte.
+pub struct SeedIter<'a> {
+    config: &'a [u8],
+}
+
+impl<'a> Iterator for SeedIter<'a> {
+    type Item = Result<Seed<'a>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let seed = match *self.config {
+            [] | [0, ..] => return None,
+            [1, len, ref rest @ ..] => rest.get(..len as usize).map(Seed::Literal),
+            [2, index, length, ..] => Some(Seed::InstructionData { index, length }),
+            [3, index, ..] => Some(Seed::AccountKey { index }),
+            [4, account_index, data_index, length, ..] => Some(Seed::AccountData {
+                account_index,
+                data_index,
+                length,
+            }),
+            _ => None,
+        };
+
+        match seed {
+            Some(seed) => {
+                self.config = &self.config[seed.packed_len()..];
+                Some(Ok(seed))
+            }
+            None => {
+                self.config = &[];
+                Some(Err(ProgramError::InvalidAccountData))
+            }
+        }
+    }
+}
@@ -5,9 +5,17 @@
 
 #[macro_use]
 pub mod macros;
+mod require;
+
+pub use require::*;
 
 use core::mem::MaybeUninit;
 use hayabusa_errors::Result;
+
+#[doc(hidden)]
+pub use hayabusa_errors::LogError;
+#[doc(hidden)]
+pub use solana_address::address_eq as __address_eq;
 use solana_address::Address;
 use solana_program_error::ProgramError;
 
@@ -13,6 +13,7 @@ macro_rules! error_msg {
     }
 }
 
+#[cfg(not(feature = "log-errors"))]
 #[macro_export]
 macro_rules! error {
     ($code:expr) => {
@@ -20,6 +21,95 @@ macro_rules! error {
     };
 }
 
+/// With `log-errors`, `#[error]` codes (and `ErrorCode`) log their name and `#[msg]` before
+/// being returned
+#[cfg(feature = "log-errors")]
+#[macro_export]
+macro_rules! error {
+    ($code:expr) => {{
+        let __code = $code;
+        $crate::LogError::log_error(&__code);
+        return Err($crate::program_error!(__code));
+    }};
+}
+
+/// Returns `$code` unless `$cond` holds
+///
+/// ```ignore
+/// require!(amount > 0, VaultError::ZeroAmount);
+/// ```
+#[macro_export]
+macro_rules! require {
+    ($cond:expr, $code:expr $(,)?) => {
+        if $crate::hint::unlikely(!($cond)) {
+            pinocchio_log::log!("require! failed: {}", stringify!($cond));
+            $crate::error!($code);
+        }
+    };
+}
+
+/// Returns `$code` unless `$left == $right`, logging both values
+#[macro_export]
+macro_rules! require_eq {
+    ($left:expr, $right:expr, $code:expr $(,)?) => {
+        $crate::require_cmp!("require_eq!", ==, $left, $right, $code)
+    };
+}
+
+/// Returns `$code` if `$left == $right`, logging both values
+#[macro_export]
+macro_rules! require_neq {
+    ($left:expr, $right:expr, $code:expr $(,)?) => {
+        $crate::require_cmp!("require_neq!", !=, $left, $right, $code)
+    };
+}
+
+/// Returns `$code` unless `$left > $right`, logging both values
+#[macro_export]
+macro_rules! require_gt {
+    ($left:expr, $right:expr, $code:expr $(,)?) => {
+        $crate::require_cmp!("require_gt!", >, $left, $right, $code)
+    };
+}
+
+/// Returns `$code` unless `$left >= $right`, logging both values
+#[macro_export]
+macro_rules! require_gte {
+    ($left:expr, $right:expr, $code:expr $(,)?) => {
+        $crate::require_cmp!("require_gte!", >=, $left, $right, $code)
+    };
+}
+
+/// Returns `$code` unless both addresses are equal, logging both of them
+#[macro_export]
+macro_rules! require_keys_eq {
+    ($left:expr, $right:expr, $code:expr $(,)?) => {
+        match (&$left, &$right) {
+            (__left, __right) => {
+                if $crate::hint::unlikely(!$crate::__address_eq(__left, __right)) {
+                    $crate::log_require_failure("require_keys_eq!", __left, __right);
+                    $crate::error!($code);
+                }
+            }
+        }
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! require_cmp {
+    ($check:literal, $op:tt, $left:expr, $right:expr, $code:expr) => {
+        match (&$left, &$right) {
+            (__left, __right) => {
+                if $crate::hint::unlikely(!(*__left $op *__right)) {
+                    $crate::log_require_failure($check, __left, __right);
+                    $crate::error!($code);
+                }
+            }
+        }
+    };
+}
+
 #[macro_export]
 macro_rules! program_error {
     ($code:expr) => {
@@ -0,0 +1,187 @@
+// Copyright (c) 2026, Arcane Labs <dev@arcane.fi>
+// SPDX-License-Identifier: Apache-2.0
+
+use solana_address::Address;
+
+/// Values `require_*!` can log when a check fails.
+pub trait LogValue {
+    fn log_value(&self, label: &str);
+}
+
+macro_rules! impl_log_value {
+    ($($t:ty),*) => {
+        $(
+            impl LogValue for $t {
+                #[inline(always)]
+                fn log_value(&self, label: &str) {
+                    pinocchio_log::log!("{}: {}", label, *self);
+                }
+            }
+        )*
+    };
+}
+
+impl_log_value!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, bool);
+
+/// Buffer size of `pinocchio_log::log!`.
+const LOG_BUFFER_LEN: usize = 200;
+
+impl LogValue for &str {
+    #[inline(always)]
+    fn log_value(&self, label: &str) {
+        match log_cut(self, label) {
+            None => pinocchio_log::log!("{}: {}", label, *self),
+            Some(cut) => pinocchio_log::log!("{}: {}@", label, cut),
+        }
+    }
+}
+
+/// The prefix of a `value` too long to log after `label`, or `None` if it fits.
+///
+/// The logger truncates bytewise, which can split a character, and the runtime rejects log
+/// lines that aren't UTF-8. The cut is made at a char boundary instead, leaving room for the
+/// logger's `@` truncation marker.
+#[inline(always)]
+fn log_cut<'a>(value: &'a str, label: &str) -> Option<&'a str> {
+    let max = LOG_BUFFER_LEN.saturating_sub(label.len() + 2);
+
+    if value.len() <= max {
+        return None;
+    }
+
+    let mut len = max.saturating_sub(1);
+    while !value.is_char_boundary(len) {
+        len -= 1;
+    }
+
+    Some(&value[..len])
+}
+
+impl LogValue for Address {
+    #[inline(always)]
+    fn log_value(&self, label: &str) {
+        pinocchio_log::log!("{}:", label);
+        hayabusa_syscalls::log_address(self);
+    }
+}
+
+impl<T: LogValue + ?Sized> LogValue for &T {
+    #[inline(always)]
+    fn log_value(&self, label: &str) {
+        (**self).log_value(label);
+    }
+}
+
+/// Logs a failed `require_*!` check and both operands.
+#[cold]
+#[inline(never)]
+pub fn log_require_failure<L, R>(check: &str, left: &L, right: &R)
+where
+    L: LogValue + ?Sized,
+    R: LogValue + ?Sized,
+{
+    pinocchio_log::log!("{} failed", check);
+    left.log_value("left");
+    right.log_value("right");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hayabusa_errors::{ErrorCode, Result};
+    use solana_program_error::ProgramError;
+
+    const KEY: Address = Address::new_from_array([7; 32]);
+
+    fn check_require(amount: u64) -> Result<()> {
+        require!(amount > 0, ProgramError::InvalidArgument);
+        Ok(())
+    }
+
+    fn check_cmp(amount: u64, name: &str) -> Result<()> {
+        require_eq!(amount % 2, 0, ErrorCode::InvalidIndex);
+        require_neq!(name, "vault", ProgramError::InvalidSeeds);
+        require_gt!(amount, 2u64, ProgramError::InsufficientFunds);
+        require_gte!(-(amount as i64), -100, ProgramError::ArithmeticOverflow);
+        Ok(())
+    }
+
+    fn check_keys(key: &Address) -> Result<()> {
+        require_keys_eq!(key, KEY, ProgramError::IncorrectProgramId);
+        require_keys_eq!(*key, &KEY, ProgramError::IncorrectProgramId);
+        Ok(())
+    }
+
+    #[test]
+    fn require_returns_code_on_failure() {
+        assert_eq!(check_require(1), Ok(()));
+        assert_eq!(check_require(0), Err(ProgramError::InvalidArgument));
+    }
+
+    #[test]
+    fn require_cmp_returns_code_on_failure() {
+        assert_eq!(check_cmp(4, "name"), Ok(()));
+        assert_eq!(check_cmp(100, "name"), Ok(()));
+
+        assert_eq!(check_cmp(3, "name"), Err(ErrorCode::InvalidIndex.into()));
+        assert_eq!(check_cmp(4, "vault"), Err(ProgramError::InvalidSeeds));
+        assert_eq!(check_cmp(2, "name"), Err(ProgramError::InsufficientFunds));
+        assert_eq!(
+            check_cmp(102, "name"),
+            Err(ProgramError::ArithmeticOverflow)
+        );
+    }
+
+    #[test]
+    fn require_keys_eq_returns_code_on_failure() {
+        assert_eq!(check_keys(&KEY), Ok(()));
+        assert_eq!(
+            check_keys(&Address::default()),
+            Err(ProgramError::IncorrectProgramId)
+        );
+    }
+
+    #[test]
+    fn log_values() {
+        u8::MAX.log_value("u8");
+        u16::MAX.log_value("u16");
+        u32::MAX.log_value("u32");
+        u64::MAX.log_value("u64");
+        u128::MAX.log_value("u128");
+        usize::MAX.log_value("usize");
+        i8::MIN.log_value("i8");
+        i16::MIN.log_value("i16");
+        i32::MIN.log_value("i32");
+        i64::MIN.log_value("i64");
+        i128::MIN.log_value("i128");
+        isize::MIN.log_value("isize");
+        true.log_value("bool");
+        "value".log_value("str");
+        KEY.log_value("address");
+        (&&KEY).log_value("reference");
+
+        // longer than the log buffer: cut at a char boundary, so the line stays UTF-8
+        let long = [0xc3, 0xa9].repeat(LOG_BUFFER_LEN);
+        let long = core::str::from_utf8(&long).unwrap();
+        long.log_value("long");
+        long.log_value("longer");
+
+        // 194 bytes fit after "long: ", less one for the `@`; 193 splits an `é`
+        assert_eq!(log_cut(long, "long").map(str::len), Some(192));
+        assert_eq!(log_cut(long, "longer").map(str::len), Some(190));
+
+        // three-byte chars, with the cut one byte past a boundary
+        let euros = "€".repeat(LOG_BUFFER_LEN);
+        let cut = log_cut(&euros, "euro").unwrap();
+        assert_eq!(cut.len(), 192);
+        assert!(cut.chars().all(|c| c == '€'));
+        assert!("euro: ".len() + cut.len() + "@".len() <= LOG_BUFFER_LEN);
+
+        // values that fit are logged whole
+        assert_eq!(log_cut(&long[..194], "long"), None);
+        assert_eq!(log_cut("value", "str"), None);
+
+        log_require_failure("require_eq!", &u64::MAX, &i128::MIN);
+        log_require_failure("require_keys_eq!", &KEY, &&KEY);
+    }
+}
@@ -57,4 +57,113 @@ pub struct CounterAccount {
 #[event]
 pub struct TestEvent {
     pub value: u64,
-}
\ No newline at end of file
+}
+
+#[cfg(all(test, feature = "client"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn update_counter_round_trip() {
+        let accounts = UpdateCounterAccounts {
+            user: Address::new_from_array([1; 32]),
+            counter: Address::new_from_array([2; 32]),
+        };
+
+        let (program_id, metas, data) = client::update_counter(&accounts, 42);
+
+        assert_eq!(program_id, crate::ID);
+        assert_eq!(metas.len(), 2);
+        assert_eq!(metas[0].pubkey, accounts.user);
+        assert!(metas[0].is_signer && !metas[0].is_writable);
+        assert_eq!(metas[1].pubkey, accounts.counter);
+        assert!(!metas[1].is_signer && metas[1].is_writable);
+
+        assert_eq!(&data[..8], instruction::UpdateCounterIx::DISCRIMINATOR);
+        assert_eq!(data[8..], 42u64.to_le_bytes());
+        let ix = <instruction::UpdateCounterIx as DecodeIx>::decode(&data[8..]).unwrap();
+        assert_eq!(ix.amount, 42);
+    }
+
+    #[test]
+    fn initialize_counter_round_trip() {
+        let accounts = InitializeCounterAccounts {
+            user: Address::new_from_array([1; 32]),
+            counter: Address::new_from_array([2; 32]),
+            system_program: Address::default(),
+        };
+
+        let (_, metas, data) = client::initialize_counter(&accounts);
+
+        assert!(metas[0].is_signer && metas[0].is_writable);
+        assert!(!metas[1].is_signer && metas[1].is_writable);
+        assert!(!metas[2].is_signer && !metas[2].is_writable);
+
+        assert_eq!(&data[..], instruction::InitializeCounterIx::DISCRIMINATOR);
+        assert!(<instruction::InitializeCounterIx as DecodeIx>::decode(&data[8..]).is_ok());
+    }
+}
+
+#[cfg(all(test, feature = "cpi"))]
+mod cpi_tests {
+    use super::*;
+    use hayabusa::prelude::account_view::{RuntimeAccount, NOT_BORROWED};
+
+    fn account(address: Address, is_signer: bool, is_writable: bool) -> RuntimeAccount {
+        RuntimeAccount {
+            borrow_state: NOT_BORROWED,
+            is_signer: is_signer as u8,
+            is_writable: is_writable as u8,
+            address,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn update_counter_cpi() {
+        let mut program = account(crate::ID, false, false);
+        let mut user = account(Address::new_from_array([1; 32]), true, false);
+        let mut counter = account(Address::new_from_array([2; 32]), false, true);
+
+        // SAFETY: the accounts carry no data
+        let (program, user, counter) = unsafe {
+            (
+                AccountView::new_unchecked(&mut program),
+                AccountView::new_unchecked(&mut user),
+                AccountView::new_unchecked(&mut counter),
+            )
+        };
+
+        let accounts = UpdateCounterCpiAccounts { user: &user, counter: &counter };
+
+        // the instruction the helper builds: user signs, counter is written
+        let metas = accounts.instruction_accounts();
+        assert_eq!(metas.len(), 2);
+        assert_eq!(
+            (metas[0].address, metas[0].is_signer, metas[0].is_writable),
+            (user.address(), true, false),
+        );
+        assert_eq!(
+            (metas[1].address, metas[1].is_signer, metas[1].is_writable),
+            (counter.address(), false, true),
+        );
+
+        let views = accounts.account_views();
+        assert_eq!(views[0].address(), user.address());
+        assert_eq!(views[1].address(), counter.address());
+
+        let cpi_ctx = CpiCtx::try_new_without_signer(&program, accounts).unwrap();
+        assert_eq!(cpi_ctx.program.address(), &crate::ID);
+        assert_eq!(cpi::update_counter(cpi_ctx, 1), Ok(()));
+
+        // the helper only invokes this program
+        assert_eq!(
+            CpiCtx::try_new_without_signer(
+                &user,
+                UpdateCounterCpiAccounts { user: &user, counter: &counter },
+            )
+            .err(),
+            Some(ProgramError::IncorrectProgramId),
+        );
+    }
+}
@@ -1,6 +1,7 @@
 #![allow(unused)]
 
-use hayabusa::prelude::Discriminator;
+use counter_program::{client, InitializeCounterAccounts, UpdateCounterAccounts};
+use hayabusa::prelude::{Address, Discriminator};
 use litesvm::LiteSVM;
 use solana_sdk::{
     account::Account, instruction::{AccountMeta, Instruction}, pubkey::Pubkey, signature::Keypair, signer::Signer, system_program, transaction::Transaction, pubkey,
@@ -34,21 +35,13 @@ fn integration() {
 
     svm.set_account(counter_account_pk, counter_account).unwrap();
 
-    let ix_data = {
-        const UPDATE_COUNTER_DISCRIMINATOR: [u8; 8] = [18, 183, 6, 47, 227, 170, 61, 195];
-        let mut data = UPDATE_COUNTER_DISCRIMINATOR.to_vec();
-        data.extend_from_slice(&1u64.to_le_bytes());
-        data
-    };
-
-    let ix = Instruction {
-        program_id,
-        accounts: vec![
-            AccountMeta::new_readonly(user, true),
-            AccountMeta::new(counter_account_pk, false),
-        ],
-        data: ix_data,
-    };
+    let ix = to_instruction(client::update_counter(
+        &UpdateCounterAccounts {
+            user: to_address(&user),
+            counter: to_address(&counter_account_pk),
+        },
+        1,
+    ));
 
     let tx = Transaction::new_signed_with_payer(&[ix], Some(&user), &[&keypair], svm.latest_blockhash());
 
@@ -76,21 +69,13 @@ fn integration2() {
 
     svm.airdrop(&user, 1_000_000_000_000).unwrap();
 
-    let ix_data = {
-        const INITIALIZE_COUNTER_DISCRIMINATOR: [u8; 8] = [184, 155, 169, 181, 122, 145, 244, 45];
-        let data = INITIALIZE_COUNTER_DISCRIMINATOR.to_vec();
-        data
-    };
-
-    let ix = Instruction {
-        program_id,
-        accounts: vec![
-            AccountMeta::new(user, true),
-            AccountMeta::new(target, true),
-            AccountMeta::new_readonly(system_program::ID, false),
-        ],
-        data: ix_data,
-    };
+    let mut ix = to_instruction(client::initialize_counter(&InitializeCounterAccounts {
+        user: to_address(&user),
+        counter: to_address(&target),
+        system_program: to_address(&system_program::ID),
+    }));
+    // the counter isn't a PDA, so its keypair signs the create_account
+    ix.accounts[1].is_signer = true;
 
     let tx = Transaction::new_signed_with_payer(&[ix], Some(&user), &[&keypair, &target_keypair], svm.latest_blockhash());
 
@@ -100,6 +85,28 @@ fn integration2() {
 
 }
 
+fn to_address(pubkey: &Pubkey) -> Address {
+    Address::new_from_array(pubkey.to_bytes())
+}
+
+/// Converts a generated `client::*` builder's output into a `solana_sdk` instruction.
+fn to_instruction(
+    (program_id, accounts, data): (Address, Vec<hayabusa::prelude::AccountMeta>, Vec<u8>),
+) -> Instruction {
+    Instruction {
+        program_id: Pubkey::new_from_array(program_id.to_bytes()),
+        accounts: accounts
+            .into_iter()
+            .map(|meta| AccountMeta {
+                pubkey: Pubkey::new_from_array(meta.pubkey.to_bytes()),
+                is_signer: meta.is_signer,
+                is_writable: meta.is_writable,
+            })
+            .collect(),
+        data,
+    }
+}
+
 fn pack_zc_account<T: bytemuck::NoUninit + Discriminator>(account: T) -> Vec<u8> {
     let mut data = T::DISCRIMINATOR.to_vec();
     data.extend_from_slice(bytemuck::bytes_of(&account));